@@ -0,0 +1,132 @@
+use cosmwasm_std::{Addr, Decimal, Decimal256};
+
+use crate::error::ContractError;
+
+// Smallest MAD used for filtering, as a fraction of the median, so a round where most
+// updaters agree exactly does not discard every submission that differs by a rounding step
+pub const MIN_MAD_FRACTION: Decimal256 = Decimal256::permille(1);
+
+// A price submitted to a multi-submitter round
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub updater: Addr,
+    pub price: Decimal256,
+    pub weight: Decimal, // The updater's reputation weight
+}
+
+// Price agreed by a round, with the updaters whose submissions were discarded
+#[derive(Clone, Debug, PartialEq)]
+pub struct Aggregate {
+    pub price: Decimal256,
+    pub survivors: Vec<Addr>,
+    pub outliers: Vec<Addr>,
+}
+
+// Median of the given prices, averaging the middle two for an even count; None when empty
+pub fn median(prices: &[Decimal256]) -> Option<Decimal256> {
+    let mut sorted = prices.to_vec();
+    sorted.sort();
+
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        len if len % 2 == 1 => Some(sorted[mid]),
+        _ => Some(sorted[mid - 1] + (sorted[mid] - sorted[mid - 1]) / Decimal256::percent(200)),
+    }
+}
+
+// Weighted median of (price, weight) pairs: the lowest price at which the cumulative weight
+// reaches half the total, averaged with the next price when it lands exactly on half.
+// Equal weights give the plain median. None when empty or without any weight.
+pub fn weighted_median(prices: &[(Decimal256, Decimal)]) -> Option<Decimal256> {
+    let mut sorted: Vec<(Decimal256, Decimal256)> = prices
+        .iter()
+        .filter(|(_, weight)| !weight.is_zero())
+        .map(|(price, weight)| (*price, Decimal256::from(*weight)))
+        .collect();
+    sorted.sort_by_key(|(price, _)| *price);
+
+    let total = sorted
+        .iter()
+        .fold(Decimal256::zero(), |acc, (_, weight)| acc + *weight);
+    let mut cumulative = Decimal256::zero();
+    for (i, (price, weight)) in sorted.iter().enumerate() {
+        cumulative += *weight;
+        if cumulative + cumulative < total {
+            continue;
+        }
+        return match sorted.get(i + 1) {
+            Some((next, _)) if cumulative + cumulative == total => {
+                Some(*price + (*next - *price) / Decimal256::percent(200))
+            }
+            _ => Some(*price),
+        };
+    }
+    None
+}
+
+// Median absolute deviation of the prices around their median; None when empty
+pub fn median_absolute_deviation(prices: &[Decimal256]) -> Option<Decimal256> {
+    let center = median(prices)?;
+    let deviations: Vec<Decimal256> = prices.iter().map(|p| p.abs_diff(center)).collect();
+    median(&deviations)
+}
+
+// Weighted median of every submission in a round
+pub fn median_aggregate(submissions: &[Submission]) -> Result<Aggregate, ContractError> {
+    let prices: Vec<(Decimal256, Decimal)> =
+        submissions.iter().map(|s| (s.price, s.weight)).collect();
+    let Some(price) = weighted_median(&prices) else {
+        return Err(ContractError::InsufficientSubmissions {
+            required: 1,
+            surviving: 0,
+        });
+    };
+
+    Ok(Aggregate {
+        price,
+        survivors: submissions.iter().map(|s| s.updater.clone()).collect(),
+        outliers: vec![],
+    })
+}
+
+// Weighted median of the submissions within `k` median absolute deviations of the round's
+// median. Filtering ignores weights, so a heavy updater is held to the same band as the rest.
+// Submissions further out are flagged as outliers. The MAD is floored at MIN_MAD_FRACTION
+// of the median. Fails unless at least `min_survivors` submissions remain.
+pub fn mad_filtered_aggregate(
+    submissions: &[Submission],
+    k: Decimal,
+    min_survivors: u32,
+) -> Result<Aggregate, ContractError> {
+    let prices: Vec<Decimal256> = submissions.iter().map(|s| s.price).collect();
+    let (Some(center), Some(mad)) = (median(&prices), median_absolute_deviation(&prices)) else {
+        return Err(ContractError::InsufficientSubmissions {
+            required: min_survivors.max(1),
+            surviving: 0,
+        });
+    };
+    let mad = mad.max(center * MIN_MAD_FRACTION);
+    // A bound beyond Decimal256 keeps every submission
+    let bound = mad.checked_mul(Decimal256::from(k)).ok();
+
+    let (survivors, outliers): (Vec<&Submission>, Vec<&Submission>) = submissions
+        .iter()
+        .partition(|s| bound.is_none_or(|bound| s.price.abs_diff(center) <= bound));
+
+    let surviving = survivors.len() as u32;
+    if survivors.is_empty() || surviving < min_survivors {
+        return Err(ContractError::InsufficientSubmissions {
+            required: min_survivors.max(1),
+            surviving,
+        });
+    }
+
+    let prices: Vec<(Decimal256, Decimal)> =
+        survivors.iter().map(|s| (s.price, s.weight)).collect();
+    Ok(Aggregate {
+        price: weighted_median(&prices).unwrap_or(center),
+        survivors: survivors.iter().map(|s| s.updater.clone()).collect(),
+        outliers: outliers.iter().map(|s| s.updater.clone()).collect(),
+    })
+}
@@ -1,28 +1,58 @@
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    coins, to_json_binary, to_json_string, Addr, BankMsg, Binary, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Response, StdError, StdResult, Storage, Uint128,
 };
-use cw_storage_plus::Bound;
+use cw2::{set_contract_version, CONTRACT};
+use cw_storage_plus::{Bound, Map};
 
+use crate::aggregation::{mad_filtered_aggregate, median_aggregate, Submission};
 use crate::error::ContractError;
 use crate::helpers::{
-    calculate_price_deviation, is_admin, is_owner, is_token_supported, is_whitelisted,
-    validate_threshold,
+    calculate_price_deviation, calculate_twap, deviation_threshold, fair_lp_price, half_life_decay,
+    is_admin, is_owner, is_token_supported, is_whitelisted, log_return, sample_std_dev,
+    transform_price, validate_composite_feed, validate_deviation_thresholds, validate_threshold,
+    whole_tokens,
 };
 use crate::msg::{
-    AllPricesResponse, ConfigResponse, ExecuteMsg, InstantiateMsg, PendingUpdate,
-    PendingUpdatesResponse, PriceHistoryEntry, PriceHistoryResponse, QueryMsg,
-    SupportedTokensResponse, TokenPrice, TokenPriceResponse, WhitelistedUpdatersResponse,
+    AggregationConfig, AllPricesResponse, AuditAction, AuditEntry, AuditLogResponse, BondResponse,
+    BondingConfig, BootstrapPolicy, CompositeFeed, ConfigResponse, DerivedFeed, DerivedInput,
+    DeviationReference, DeviationThresholds, EmaResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    PendingRewardsResponse, PendingUpdate, PendingUpdatesResponse, PriceData, PriceHistoryEntry,
+    PriceHistoryResponse, PriceStatsResponse, PriceStatus, QueryMsg, QueuedChange,
+    QueuedChangesResponse, Resolution, ResolvedUpdate, ResolvedUpdatesResponse, RewardConfig,
+    RewardPoolResponse, SimulateUpdateResponse, SimulatedOutcome, SimulatedPrice, Submitter,
+    SudoMsg, SupportedTokensResponse, TokenConfigResponse, TokenPrice, TokenPriceItem,
+    TokenPriceResponse, TokenPricesResponse, TransformedPriceResponse, TwapResponse,
+    UpdaterStatusResponse, UpdaterStatusesResponse, UpdaterWeight, UpdaterWeightsResponse,
+    WhitelistedUpdatersResponse,
 };
 use crate::state::{
-    Config, TokenInfo, CONFIG, PENDING_UPDATES, PRICE_HISTORY, PRICES, TOKENS, WHITELISTED_UPDATERS,
+    BootstrapSubmission, Config, EmaValue, PriceRecord, TokenInfo, ACCRUED_REWARDS, AGGREGATION,
+    AUDIT_LOG, AUDIT_LOG_BY_ACTION, AUDIT_LOG_NEXT_ID, BONDING, BONDS, BOOTSTRAP_POLICY,
+    BOOTSTRAP_SUBMISSIONS, COMPOSITE_FEEDS, CONFIG, DERIVED_FEEDS, DERIVED_INPUTS, EMAS,
+    EMA_PERIODS, FEED_DEPENDENTS, PENDING_DERIVED_INPUTS, PENDING_UPDATES,
+    PENDING_UPDATES_BY_TOKEN, PENDING_UPDATES_BY_UPDATER, PENDING_UPDATES_NEXT_ID, PRICES,
+    PRICE_HISTORY, QUEUED_CHANGES, QUEUED_CHANGES_NEXT_ID, RESOLVED_UPDATES,
+    RESOLVED_UPDATES_NEXT_ID, REWARDS, REWARD_POOL, TOKENS, TOTAL_BONDED, TOTAL_UPDATER_WEIGHT,
+    UPDATER_STATS, WHITELISTED_UPDATERS,
 };
 
-use cosmwasm_std::Decimal;
-use std::collections::HashMap;
+use cosmwasm_std::{Decimal, Decimal256};
 
 // Constants
+const CONTRACT_NAME: &str = "crates.io:gg-oracle-price";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const DEFAULT_HISTORY_LIMIT: u32 = 100;
+const DEFAULT_UPDATER_WEIGHT: Decimal = Decimal::one();
 const MAX_HISTORY_LIMIT: u32 = 1000;
+const DEFAULT_PAGE_LIMIT: u32 = 30;
+const MAX_PAGE_LIMIT: u32 = 100;
+const MAX_EMA_PERIODS: usize = 10;
+const MAX_REJECTION_REASON_LENGTH: usize = 256;
+const MAX_PENDING_UPDATES_PER_TOKEN: usize = 10;
+const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+const MAX_TOKEN_DECIMALS: u8 = 36;
 
 // Instantiate the contract
 pub fn instantiate(
@@ -51,13 +81,17 @@ pub fn instantiate(
         owner,
         admin,
         price_deviation_threshold,
+        paused: false,
+        guardian: None,
+        timelock_delay: 0,
     };
     CONFIG.save(deps.storage, &config)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     // Store whitelisted updaters
     for updater in msg.whitelisted_updaters {
         let addr = deps.api.addr_validate(&updater)?;
-        WHITELISTED_UPDATERS.save(deps.storage, &addr, &true)?;
+        save_updater_weight(deps.storage, &addr, Some(DEFAULT_UPDATER_WEIGHT))?;
     }
 
     // Store supported tokens
@@ -65,7 +99,11 @@ pub fn instantiate(
         TOKENS.save(
             deps.storage,
             &token_id,
-            &TokenInfo { supported: true },
+            &TokenInfo {
+                deviation_reference: None,
+                deviation_thresholds: None,
+                decimals: None,
+            },
         )?;
     }
 
@@ -82,26 +120,99 @@ pub fn execute(
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    // Risk parameter changes are queued while a timelock delay is set
+    if msg.is_timelocked() {
+        let delay = CONFIG.load(deps.storage)?.timelock_delay;
+        if delay > 0 {
+            return execute_queue_change(deps, env, info, msg, delay);
+        }
+    }
+
+    dispatch(deps, env, info, msg)
+}
+
+// Route a message to its handler
+fn dispatch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
         // Admin functions
-        ExecuteMsg::UpdateOwner { new_owner } => execute_update_owner(deps, info, new_owner),
-        ExecuteMsg::UpdateAdmin { new_admin } => execute_update_admin(deps, info, new_admin),
+        ExecuteMsg::UpdateOwner { new_owner } => execute_update_owner(deps, env, info, new_owner),
+        ExecuteMsg::UpdateAdmin { new_admin } => execute_update_admin(deps, env, info, new_admin),
         ExecuteMsg::SetDeviationThreshold { threshold } => {
-            execute_set_deviation_threshold(deps, info, threshold)
+            execute_set_deviation_threshold(deps, env, info, threshold)
         }
         ExecuteMsg::AddWhitelistedUpdater { updater } => {
-            execute_add_whitelisted_updater(deps, info, updater)
+            execute_add_whitelisted_updater(deps, env, info, updater)
         }
         ExecuteMsg::RemoveWhitelistedUpdater { updater } => {
-            execute_remove_whitelisted_updater(deps, info, updater)
+            execute_remove_whitelisted_updater(deps, env, info, updater)
         }
+        ExecuteMsg::SetUpdaterWeight { updater, weight } => {
+            execute_set_updater_weight(deps, env, info, updater, weight)
+        }
+        ExecuteMsg::Pause {} => execute_set_paused(deps, env, info, true),
+        ExecuteMsg::Unpause {} => execute_set_paused(deps, env, info, false),
         ExecuteMsg::AddSupportedToken { token_id } => {
-            execute_add_supported_token(deps, info, token_id)
+            execute_add_supported_token(deps, env, info, token_id)
         }
         ExecuteMsg::RemoveSupportedToken { token_id } => {
-            execute_remove_supported_token(deps, info, token_id)
+            execute_remove_supported_token(deps, env, info, token_id)
+        }
+        ExecuteMsg::SetEmaPeriods { half_lives } => {
+            execute_set_ema_periods(deps, env, info, half_lives)
+        }
+        ExecuteMsg::SetDeviationReference {
+            token_id,
+            reference,
+        } => execute_set_deviation_reference(deps, env, info, token_id, reference),
+        ExecuteMsg::SetTokenDeviationThresholds {
+            token_id,
+            thresholds,
+        } => execute_set_token_deviation_thresholds(deps, env, info, token_id, thresholds),
+        ExecuteMsg::SetCompositeFeed { token_id, feed } => {
+            execute_set_composite_feed(deps, env, info, token_id, feed)
+        }
+        ExecuteMsg::SetDerivedFeed { token_id, feed } => {
+            execute_set_derived_feed(deps, env, info, token_id, feed)
         }
+        ExecuteMsg::SetTokenDecimals { token_id, decimals } => {
+            execute_set_token_decimals(deps, env, info, token_id, decimals)
+        }
+        ExecuteMsg::SetBootstrapPolicy { policy } => {
+            execute_set_bootstrap_policy(deps, env, info, policy)
+        }
+        ExecuteMsg::SetAggregationConfig { config } => {
+            execute_set_aggregation_config(deps, env, info, config)
+        }
+
+        // Timelock functions
+        ExecuteMsg::SetTimelockDelay { delay_seconds } => {
+            execute_set_timelock_delay(deps, env, info, delay_seconds)
+        }
+        ExecuteMsg::SetGuardian { guardian } => execute_set_guardian(deps, env, info, guardian),
+        ExecuteMsg::ExecuteQueued { id } => execute_queued(deps, env, id),
+        ExecuteMsg::CancelQueued { id } => execute_cancel_queued(deps, env, info, id),
+
+        // Bonding functions
+        ExecuteMsg::SetBondingConfig { config } => {
+            execute_set_bonding_config(deps, env, info, config)
+        }
+        ExecuteMsg::Bond {} => execute_bond(deps, info),
+        ExecuteMsg::Unbond { amount } => execute_unbond(deps, env, info, amount),
+        ExecuteMsg::ClaimUnbonded {} => execute_claim_unbonded(deps, env, info),
+        ExecuteMsg::Slash { updater, amount } => execute_slash(deps, env, info, updater, amount),
+
+        // Reward functions
+        ExecuteMsg::SetRewardConfig { config } => {
+            execute_set_reward_config(deps, env, info, config)
+        }
+        ExecuteMsg::FundRewards {} => execute_fund_rewards(deps, info),
+        ExecuteMsg::ClaimRewards {} => execute_claim_rewards(deps, info),
 
         // Price update functions
         ExecuteMsg::UpdatePrices { price_data } => {
@@ -111,18 +222,30 @@ pub fn execute(
             token_id,
             price_info,
         } => execute_update_single_price(deps, env, info, token_id, price_info),
+        ExecuteMsg::UpdateDerivedInput { token_id, input } => {
+            execute_update_derived_input(deps, env, info, token_id, input)
+        }
 
         // Manual admin actions
         ExecuteMsg::ApprovePrice { token_id, price } => {
             execute_approve_price(deps, env, info, token_id, price)
         }
-        ExecuteMsg::RejectPrice { token_id } => execute_reject_price(deps, info, token_id),
+        ExecuteMsg::RejectPrice { token_id, reason } => {
+            execute_reject_price(deps, env, info, token_id, reason)
+        }
+        ExecuteMsg::ApprovePendingUpdate { id, price } => {
+            execute_approve_pending_update(deps, env, info, id, price)
+        }
+        ExecuteMsg::RejectPendingUpdate { id, reason } => {
+            execute_reject_pending_update(deps, env, info, id, reason)
+        }
     }
 }
 
 // Update owner - Only current owner can call this
 fn execute_update_owner(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     new_owner: String,
 ) -> Result<Response, ContractError> {
@@ -131,14 +254,33 @@ fn execute_update_owner(
         return Err(ContractError::Unauthorized {});
     }
 
+    update_owner(deps, &env, Some(&info.sender), new_owner)
+}
+
+// Replace the owner, shared by execute and sudo
+fn update_owner(
+    deps: DepsMut,
+    env: &Env,
+    actor: Option<&Addr>,
+    new_owner: String,
+) -> Result<Response, ContractError> {
     // Validate new owner address
     let new_owner_addr = deps.api.addr_validate(&new_owner)?;
 
     // Update config
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.owner = new_owner_addr.clone();
-        Ok(config)
-    })?;
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_owner = std::mem::replace(&mut config.owner, new_owner_addr.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    record_audit(
+        deps.storage,
+        env,
+        actor,
+        AuditAction::UpdateOwner,
+        None,
+        Some(old_owner.to_string()),
+        Some(new_owner_addr.to_string()),
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "update_owner")
@@ -148,6 +290,7 @@ fn execute_update_owner(
 // Update admin - Only owner can call this
 fn execute_update_admin(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     new_admin: String,
 ) -> Result<Response, ContractError> {
@@ -156,14 +299,33 @@ fn execute_update_admin(
         return Err(ContractError::Unauthorized {});
     }
 
+    update_admin(deps, &env, Some(&info.sender), new_admin)
+}
+
+// Replace the admin, shared by execute and sudo
+fn update_admin(
+    deps: DepsMut,
+    env: &Env,
+    actor: Option<&Addr>,
+    new_admin: String,
+) -> Result<Response, ContractError> {
     // Validate new admin address
     let new_admin_addr = deps.api.addr_validate(&new_admin)?;
 
     // Update config
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.admin = new_admin_addr.clone();
-        Ok(config)
-    })?;
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_admin = std::mem::replace(&mut config.admin, new_admin_addr.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    record_audit(
+        deps.storage,
+        env,
+        actor,
+        AuditAction::UpdateAdmin,
+        None,
+        Some(old_admin.to_string()),
+        Some(new_admin_addr.to_string()),
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "update_admin")
@@ -173,6 +335,7 @@ fn execute_update_admin(
 // Set price deviation threshold - Only admin can call this
 fn execute_set_deviation_threshold(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     threshold: Decimal,
 ) -> Result<Response, ContractError> {
@@ -185,10 +348,19 @@ fn execute_set_deviation_threshold(
     validate_threshold(threshold)?;
 
     // Update config
-    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
-        config.price_deviation_threshold = threshold;
-        Ok(config)
-    })?;
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_threshold = std::mem::replace(&mut config.price_deviation_threshold, threshold);
+    CONFIG.save(deps.storage, &config)?;
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetDeviationThreshold,
+        None,
+        Some(old_threshold.to_string()),
+        Some(threshold.to_string()),
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "set_deviation_threshold")
@@ -198,6 +370,7 @@ fn execute_set_deviation_threshold(
 // Add whitelisted updater - Only admin can call this
 fn execute_add_whitelisted_updater(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     updater: String,
 ) -> Result<Response, ContractError> {
@@ -206,28 +379,44 @@ fn execute_add_whitelisted_updater(
         return Err(ContractError::Unauthorized {});
     }
 
-    // Validate updater address
-    let updater_addr = deps.api.addr_validate(&updater)?;
-
-    // Check if already whitelisted
-    if WHITELISTED_UPDATERS
-        .may_load(deps.storage, &updater_addr)?
-        .unwrap_or(false)
-    {
-        return Err(ContractError::UpdaterAlreadyWhitelisted(updater));
-    }
+    let updater_addr = check_add_updater(deps.as_ref(), &updater)?;
 
     // Add to whitelist
-    WHITELISTED_UPDATERS.save(deps.storage, &updater_addr, &true)?;
+    save_updater_weight(deps.storage, &updater_addr, Some(DEFAULT_UPDATER_WEIGHT))?;
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::AddWhitelistedUpdater,
+        None,
+        None,
+        Some(updater_addr.to_string()),
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "add_whitelisted_updater")
         .add_attribute("updater", updater))
 }
 
+// Validate an updater address that is not yet whitelisted
+fn check_add_updater(deps: Deps, updater: &str) -> Result<Addr, ContractError> {
+    let updater_addr = deps.api.addr_validate(updater)?;
+
+    // Check if already whitelisted
+    if WHITELISTED_UPDATERS.has(deps.storage, &updater_addr) {
+        return Err(ContractError::UpdaterAlreadyWhitelisted(
+            updater.to_string(),
+        ));
+    }
+
+    Ok(updater_addr)
+}
+
 // Remove whitelisted updater - Only admin can call this
 fn execute_remove_whitelisted_updater(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     updater: String,
 ) -> Result<Response, ContractError> {
@@ -236,298 +425,2971 @@ fn execute_remove_whitelisted_updater(
         return Err(ContractError::Unauthorized {});
     }
 
+    remove_whitelisted_updater(deps, &env, Some(&info.sender), updater)
+}
+
+// Remove an updater from the whitelist, shared by execute and sudo
+fn remove_whitelisted_updater(
+    deps: DepsMut,
+    env: &Env,
+    actor: Option<&Addr>,
+    updater: String,
+) -> Result<Response, ContractError> {
     // Validate updater address
     let updater_addr = deps.api.addr_validate(&updater)?;
 
     // Check if whitelisted
-    if !WHITELISTED_UPDATERS
-        .may_load(deps.storage, &updater_addr)?
-        .unwrap_or(false)
-    {
+    if !WHITELISTED_UPDATERS.has(deps.storage, &updater_addr) {
         return Err(ContractError::UpdaterNotWhitelisted(updater));
     }
 
     // Remove from whitelist
-    WHITELISTED_UPDATERS.remove(deps.storage, &updater_addr);
+    save_updater_weight(deps.storage, &updater_addr, None)?;
+
+    record_audit(
+        deps.storage,
+        env,
+        actor,
+        AuditAction::RemoveWhitelistedUpdater,
+        None,
+        Some(updater_addr.to_string()),
+        None,
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "remove_whitelisted_updater")
         .add_attribute("updater", updater))
 }
 
-// Add supported token - Only admin can call this
-fn execute_add_supported_token(
+// Set an updater's weight in round aggregation - Only admin can call this
+fn execute_set_updater_weight(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    token_id: String,
+    updater: String,
+    weight: Decimal,
 ) -> Result<Response, ContractError> {
     // Check if sender is admin or owner
     if !is_admin(deps.as_ref(), &info.sender)? {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Check if already supported
-    if is_token_supported(deps.as_ref(), &token_id)? {
-        return Err(ContractError::TokenAlreadySupported(token_id));
-    }
+    let (updater_addr, old_weight) = check_updater_weight(deps.as_ref(), &updater, weight)?;
+    save_updater_weight(deps.storage, &updater_addr, Some(weight))?;
 
-    // Add to supported tokens
-    TOKENS.save(
+    record_audit(
         deps.storage,
-        &token_id,
-        &TokenInfo { supported: true },
+        &env,
+        Some(&info.sender),
+        AuditAction::SetUpdaterWeight,
+        None,
+        Some(format!("{}:{}", updater_addr, old_weight)),
+        Some(format!("{}:{}", updater_addr, weight)),
     )?;
 
     Ok(Response::new()
-        .add_attribute("method", "add_supported_token")
-        .add_attribute("token_id", token_id))
+        .add_attribute("method", "set_updater_weight")
+        .add_attribute("updater", updater)
+        .add_attribute("weight", weight.to_string()))
 }
 
-// Remove supported token - Only admin can call this
-fn execute_remove_supported_token(
+// Validate a new weight for a whitelisted updater, returning its address and current weight
+fn check_updater_weight(
+    deps: Deps,
+    updater: &str,
+    weight: Decimal,
+) -> Result<(Addr, Decimal), ContractError> {
+    let updater_addr = deps.api.addr_validate(updater)?;
+
+    // Check if whitelisted
+    let Some(old_weight) = WHITELISTED_UPDATERS.may_load(deps.storage, &updater_addr)? else {
+        return Err(ContractError::UpdaterNotWhitelisted(updater.to_string()));
+    };
+
+    // Removing an updater is done through the whitelist
+    if weight.is_zero() {
+        return Err(ContractError::InvalidUpdaterWeight {});
+    }
+
+    Ok((updater_addr, old_weight))
+}
+
+// Whitelist an updater with a weight, or remove it with None, keeping the total weight in step
+fn save_updater_weight(
+    storage: &mut dyn Storage,
+    updater: &Addr,
+    weight: Option<Decimal>,
+) -> StdResult<()> {
+    let old_weight = WHITELISTED_UPDATERS
+        .may_load(storage, updater)?
+        .unwrap_or_default();
+    match weight {
+        Some(weight) => WHITELISTED_UPDATERS.save(storage, updater, &weight)?,
+        None => WHITELISTED_UPDATERS.remove(storage, updater),
+    }
+
+    let total = TOTAL_UPDATER_WEIGHT.may_load(storage)?.unwrap_or_default();
+    TOTAL_UPDATER_WEIGHT.save(
+        storage,
+        &total
+            .checked_sub(old_weight)?
+            .checked_add(weight.unwrap_or_default())?,
+    )
+}
+
+// Weight of an updater's bootstrap quorum submissions: its whitelisted weight, the default
+// for the owner and admin, and zero once it has left the whitelist
+fn updater_weight(storage: &dyn Storage, updater: &Addr) -> StdResult<Decimal> {
+    if let Some(weight) = WHITELISTED_UPDATERS.may_load(storage, updater)? {
+        return Ok(weight);
+    }
+    let config = CONFIG.load(storage)?;
+    if *updater == config.admin || *updater == config.owner {
+        Ok(DEFAULT_UPDATER_WEIGHT)
+    } else {
+        Ok(Decimal::zero())
+    }
+}
+
+// Pause or unpause price writes - Only admin can call this
+fn execute_set_paused(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    token_id: String,
+    paused: bool,
 ) -> Result<Response, ContractError> {
     // Check if sender is admin or owner
     if !is_admin(deps.as_ref(), &info.sender)? {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Check if supported
-    if !is_token_supported(deps.as_ref(), &token_id)? {
-        return Err(ContractError::TokenNotSupported(token_id));
-    }
+    set_paused(deps, &env, Some(&info.sender), paused)
+}
+
+// Set the paused flag, shared by execute and sudo
+fn set_paused(
+    deps: DepsMut,
+    env: &Env,
+    actor: Option<&Addr>,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let was_paused = std::mem::replace(&mut config.paused, paused);
+    CONFIG.save(deps.storage, &config)?;
 
-    // Mark as not supported
-    TOKENS.update(
+    record_audit(
         deps.storage,
-        &token_id,
-        |token_info| -> StdResult<_> {
-            let mut token = token_info.unwrap();
-            token.supported = false;
-            Ok(token)
+        env,
+        actor,
+        if paused {
+            AuditAction::Pause
+        } else {
+            AuditAction::Unpause
         },
+        None,
+        Some(was_paused.to_string()),
+        Some(paused.to_string()),
     )?;
 
-    // Optionally remove from active prices
-    PRICES.remove(deps.storage, &token_id);
-
-    // Optionally remove pending updates
-    PENDING_UPDATES.remove(deps.storage, &token_id);
-
-    Ok(Response::new()
-        .add_attribute("method", "remove_supported_token")
-        .add_attribute("token_id", token_id))
+    Ok(Response::new().add_attribute("method", if paused { "pause" } else { "unpause" }))
 }
 
-// Update prices - Only whitelisted updaters can call this
-fn execute_update_prices(
+// Add supported token - Only admin can call this
+fn execute_add_supported_token(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    price_data: crate::msg::PriceData,
+    token_id: String,
 ) -> Result<Response, ContractError> {
-    // Check if sender is whitelisted
-    if !is_whitelisted(deps.as_ref(), &info.sender)? {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
         return Err(ContractError::Unauthorized {});
     }
 
-    let mut response = Response::new().add_attribute("method", "update_prices");
-    let config = CONFIG.load(deps.storage)?;
-    let current_time = env.block.time.seconds();
-
-    // Process each price update
-    for (token_id, price_info) in price_data.prices {
-        // Check if token is supported
-        if !is_token_supported(deps.as_ref(), &token_id)? {
-            return Err(ContractError::TokenNotSupported(token_id));
-        }
-
-        let current_price_opt = PRICES.may_load(deps.storage, &token_id)?;
-
-        // If we have a current price, check for deviation
-        if let Some(current_price) = current_price_opt {
-            let deviation = calculate_price_deviation(current_price.usd, price_info.usd)?;
+    check_add_token(deps.as_ref(), &token_id)?;
 
-            // If price deviation exceeds threshold, add to pending updates
-            if deviation > config.price_deviation_threshold {
-                let pending_update = PendingUpdate {
-                    token_id: token_id.clone(),
-                    current_price: current_price.usd,
-                    new_price: price_info.usd,
-                    percent_change: deviation,
-                    requested_at: current_time,
-                };
+    // Add to supported tokens
+    TOKENS.save(
+        deps.storage,
+        &token_id,
+        &TokenInfo {
+            deviation_reference: None,
+            deviation_thresholds: None,
+            decimals: None,
+        },
+    )?;
 
-                PENDING_UPDATES.save(deps.storage, &token_id, &pending_update)?;
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::AddSupportedToken,
+        Some(&token_id),
+        None,
+        None,
+    )?;
 
-                response = response.add_attribute("token_pending", &token_id);
-                continue;
-            }
-        }
+    Ok(Response::new()
+        .add_attribute("method", "add_supported_token")
+        .add_attribute("token_id", token_id))
+}
 
-        // Update price and add to history
-        let token_price = TokenPrice {
-            usd: price_info.usd,
-            last_updated: current_time,
-        };
+// Check a token is not yet supported
+fn check_add_token(deps: Deps, token_id: &str) -> Result<(), ContractError> {
+    if is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenAlreadySupported(token_id.to_string()));
+    }
+    Ok(())
+}
 
-        PRICES.save(deps.storage, &token_id, &token_price)?;
-        PRICE_HISTORY.save(deps.storage, (&token_id, current_time), &price_info.usd)?;
+// Check a token is supported and no composite or derived feed is built on it
+fn check_remove_token(deps: Deps, token_id: &str) -> Result<(), ContractError> {
+    if !is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.to_string()));
+    }
 
-        response = response.add_attribute("token_updated", &token_id);
+    if let Some(dependent) = FEED_DEPENDENTS
+        .prefix(token_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .transpose()?
+    {
+        return Err(ContractError::TokenInUse {
+            token_id: token_id.to_string(),
+            dependent,
+        });
     }
 
-    Ok(response)
+    Ok(())
 }
 
-// Update single price - Only whitelisted updaters can call this
-fn execute_update_single_price(
+// Remove supported token - Only admin can call this
+fn execute_remove_supported_token(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     token_id: String,
-    price_info: crate::msg::TokenPriceInfo,
 ) -> Result<Response, ContractError> {
-    // Check if sender is whitelisted
-    if !is_whitelisted(deps.as_ref(), &info.sender)? {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Check if token is supported
-    if !is_token_supported(deps.as_ref(), &token_id)? {
-        return Err(ContractError::TokenNotSupported(token_id.clone()));
-    }
-
-    let config = CONFIG.load(deps.storage)?;
-    let current_time = env.block.time.seconds();
-    let current_price_opt = PRICES.may_load(deps.storage, &token_id)?;
-
-    // If we have a current price, check for deviation
-    if let Some(current_price) = current_price_opt {
-        let deviation = calculate_price_deviation(current_price.usd, price_info.usd)?;
+    check_remove_token(deps.as_ref(), &token_id)?;
+    remove_composite_feed(deps.storage, &token_id)?;
+    remove_derived_feed(deps.storage, &token_id)?;
 
-        // If price deviation exceeds threshold, add to pending updates
-        if deviation > config.price_deviation_threshold {
-            let pending_update = PendingUpdate {
-                token_id: token_id.clone(),
-                current_price: current_price.usd,
-                new_price: price_info.usd,
-                percent_change: deviation,
-                requested_at: current_time,
-            };
+    // Remove the token, so listing supported tokens never walks removed ones
+    TOKENS.remove(deps.storage, &token_id);
 
-            PENDING_UPDATES.save(deps.storage, &token_id, &pending_update)?;
+    // Optionally remove from active prices
+    PRICES.remove(deps.storage, &token_id);
+    clear_bootstrap_submissions(deps.storage, &token_id)?;
 
-            return Ok(Response::new()
-                .add_attribute("method", "update_single_price")
-                .add_attribute("token_id", token_id)
-                .add_attribute("status", "pending_approval")
-                .add_attribute("deviation", deviation.to_string()));
-        }
+    // Drop the token's history and averages, so adding it again starts from scratch
+    let timestamps: Vec<u64> = PRICE_HISTORY
+        .prefix(&token_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for timestamp in timestamps {
+        PRICE_HISTORY.remove(deps.storage, (&token_id, timestamp));
+    }
+    let half_lives: Vec<u64> = EMAS
+        .prefix(&token_id)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for half_life in half_lives {
+        EMAS.remove(deps.storage, (&token_id, half_life));
     }
 
-    // Update price and add to history
-    let token_price = TokenPrice {
-        usd: price_info.usd,
-        last_updated: current_time,
-    };
+    // Optionally remove pending updates
+    for id in pending_update_ids(deps.storage, &token_id)? {
+        let update = PENDING_UPDATES.load(deps.storage, id)?;
+        remove_pending_update(deps.storage, &update);
+    }
 
-    PRICES.save(deps.storage, &token_id, &token_price)?;
-    PRICE_HISTORY.save(deps.storage, (&token_id, current_time), &price_info.usd)?;
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::RemoveSupportedToken,
+        Some(&token_id),
+        None,
+        None,
+    )?;
 
     Ok(Response::new()
-        .add_attribute("method", "update_single_price")
-        .add_attribute("token_id", token_id)
-        .add_attribute("price", price_info.usd.to_string())
-        .add_attribute("status", "updated"))
+        .add_attribute("method", "remove_supported_token")
+        .add_attribute("token_id", token_id))
 }
 
-// Approve pending price - Only admin can call this
-fn execute_approve_price(
+// Set EMA half-lives - Only admin can call this
+fn execute_set_ema_periods(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    token_id: String,
-    price: Decimal,
+    half_lives: Vec<u64>,
 ) -> Result<Response, ContractError> {
     // Check if sender is admin or owner
     if !is_admin(deps.as_ref(), &info.sender)? {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Check if token is supported
-    if !is_token_supported(deps.as_ref(), &token_id)? {
-        return Err(ContractError::TokenNotSupported(token_id.clone()));
-    }
+    let half_lives = check_ema_periods(deps.as_ref(), half_lives)?;
 
-    // Check if there's a pending update
-    let pending_update = PENDING_UPDATES.may_load(deps.storage, &token_id)?;
-    if pending_update.is_none() {
-        return Err(ContractError::NoPendingUpdate(token_id));
-    }
+    let old_half_lives = EMA_PERIODS.may_load(deps.storage)?.unwrap_or_default();
+    let removed: Vec<u64> = old_half_lives
+        .iter()
+        .copied()
+        .filter(|h| !half_lives.contains(h))
+        .collect();
 
-    let current_time = env.block.time.seconds();
+    EMA_PERIODS.save(deps.storage, &half_lives)?;
 
-    // Update price and add to history
-    let token_price = TokenPrice {
-        usd: price,
-        last_updated: current_time,
-    };
+    // Drop the values of removed EMAs so a re-added half-life starts fresh
+    let stale: Vec<(String, u64)> = EMAS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .filter(|key| key.as_ref().map_or(true, |(_, h)| removed.contains(h)))
+        .collect::<StdResult<_>>()?;
+    for (token_id, half_life) in stale {
+        EMAS.remove(deps.storage, (&token_id, half_life));
+    }
 
-    PRICES.save(deps.storage, &token_id, &token_price)?;
-    PRICE_HISTORY.save(deps.storage, (&token_id, current_time), &price)?;
+    let join = |half_lives: &[u64]| -> String {
+        let half_lives_str: Vec<String> = half_lives.iter().map(|h| h.to_string()).collect();
+        half_lives_str.join(",")
+    };
 
-    // Remove pending update
-    PENDING_UPDATES.remove(deps.storage, &token_id);
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetEmaPeriods,
+        None,
+        Some(join(&old_half_lives)),
+        Some(join(&half_lives)),
+    )?;
 
     Ok(Response::new()
-        .add_attribute("method", "approve_price")
-        .add_attribute("token_id", token_id)
-        .add_attribute("price", price.to_string()))
+        .add_attribute("method", "set_ema_periods")
+        .add_attribute("half_lives", join(&half_lives)))
 }
 
-// Reject pending price - Only admin can call this
-fn execute_reject_price(
+// Validate EMA half-lives, returning them sorted and deduplicated
+fn check_ema_periods(deps: Deps, mut half_lives: Vec<u64>) -> Result<Vec<u64>, ContractError> {
+    half_lives.sort_unstable();
+    half_lives.dedup();
+    if half_lives.contains(&0) {
+        return Err(ContractError::InvalidEmaPeriods(
+            "Half-life must be greater than zero".to_string(),
+        ));
+    }
+    if half_lives.len() > MAX_EMA_PERIODS {
+        return Err(ContractError::InvalidEmaPeriods(format!(
+            "At most {} half-lives can be configured",
+            MAX_EMA_PERIODS
+        )));
+    }
+
+    // A removed EMA stops updating, so no token may still check deviations against it
+    for item in TOKENS.range(deps.storage, None, None, Order::Ascending) {
+        let (token_id, token) = item?;
+        if let Some(DeviationReference::Ema { period }) = token.deviation_reference {
+            if !half_lives.contains(&period) {
+                return Err(ContractError::InvalidEmaPeriods(format!(
+                    "Half-life {} is the deviation reference of token {}",
+                    period, token_id
+                )));
+            }
+        }
+    }
+
+    Ok(half_lives)
+}
+
+// Set the deviation reference for a token - Only admin can call this
+fn execute_set_deviation_reference(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     token_id: String,
+    reference: DeviationReference,
 ) -> Result<Response, ContractError> {
     // Check if sender is admin or owner
     if !is_admin(deps.as_ref(), &info.sender)? {
         return Err(ContractError::Unauthorized {});
     }
 
-    // Check if there's a pending update
-    let pending_update = PENDING_UPDATES.may_load(deps.storage, &token_id)?;
-    if pending_update.is_none() {
-        return Err(ContractError::NoPendingUpdate(token_id));
-    }
+    check_deviation_reference(deps.as_ref(), &token_id, &reference)?;
+
+    let mut token = TOKENS.load(deps.storage, &token_id)?;
+    let old_reference = std::mem::replace(
+        &mut token.deviation_reference,
+        match &reference {
+            DeviationReference::LastPrice {} => None,
+            reference => Some(reference.clone()),
+        },
+    );
+    TOKENS.save(deps.storage, &token_id, &token)?;
 
-    // Remove pending update
-    PENDING_UPDATES.remove(deps.storage, &token_id);
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetDeviationReference,
+        Some(&token_id),
+        Some(to_json_string(
+            &old_reference.unwrap_or(DeviationReference::LastPrice {}),
+        )?),
+        Some(to_json_string(&reference)?),
+    )?;
 
     Ok(Response::new()
-        .add_attribute("method", "reject_price")
+        .add_attribute("method", "set_deviation_reference")
         .add_attribute("token_id", token_id))
 }
 
+// Validate a deviation reference for a supported token
+fn check_deviation_reference(
+    deps: Deps,
+    token_id: &str,
+    reference: &DeviationReference,
+) -> Result<(), ContractError> {
+    if !is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.to_string()));
+    }
+
+    match reference {
+        DeviationReference::LastPrice {} => {}
+        DeviationReference::Twap { window_seconds } => {
+            if *window_seconds == 0 {
+                return Err(ContractError::InvalidDeviationReference(
+                    "TWAP window must be greater than zero".to_string(),
+                ));
+            }
+        }
+        DeviationReference::Ema { period } => {
+            if !EMA_PERIODS
+                .may_load(deps.storage)?
+                .unwrap_or_default()
+                .contains(period)
+            {
+                return Err(ContractError::InvalidDeviationReference(format!(
+                    "EMA period {} not configured",
+                    period
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Set per-token deviation thresholds - Only admin can call this
+fn execute_set_token_deviation_thresholds(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    thresholds: Option<DeviationThresholds>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    check_token_deviation_thresholds(deps.as_ref(), &token_id, thresholds.as_ref())?;
+
+    let mut token = TOKENS.load(deps.storage, &token_id)?;
+    let old_thresholds = std::mem::replace(&mut token.deviation_thresholds, thresholds.clone());
+    TOKENS.save(deps.storage, &token_id, &token)?;
+
+    // None means the token uses the global threshold
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetTokenDeviationThresholds,
+        Some(&token_id),
+        old_thresholds.as_ref().map(to_json_string).transpose()?,
+        thresholds.as_ref().map(to_json_string).transpose()?,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_token_deviation_thresholds")
+        .add_attribute("token_id", token_id))
+}
+
+// Set or clear a token's composite feed - Only admin can call this
+fn execute_set_composite_feed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    feed: Option<CompositeFeed>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    check_composite_feed(deps.as_ref(), &token_id, feed.as_ref())?;
+
+    // A token is either a composite or a derived feed
+    let old_feed = remove_composite_feed(deps.storage, &token_id)?;
+    remove_derived_feed(deps.storage, &token_id)?;
+    if let Some(feed) = &feed {
+        COMPOSITE_FEEDS.save(deps.storage, &token_id, feed)?;
+        for component in &feed.components {
+            FEED_DEPENDENTS.save(deps.storage, (&component.token_id, &token_id), &Empty {})?;
+        }
+
+        // Price the feed right away when every component has a price
+        if let Some(price) = composite_price(deps.storage, feed)? {
+            write_price(
+                deps.storage,
+                &token_id,
+                price,
+                env.block.time.seconds(),
+                Submitter::Derived {},
+            )?;
+        }
+    }
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetCompositeFeed,
+        Some(&token_id),
+        old_feed.as_ref().map(to_json_string).transpose()?,
+        feed.as_ref().map(to_json_string).transpose()?,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_composite_feed")
+        .add_attribute("token_id", token_id))
+}
+
+// Validate per-token deviation thresholds for a supported token
+fn check_token_deviation_thresholds(
+    deps: Deps,
+    token_id: &str,
+    thresholds: Option<&DeviationThresholds>,
+) -> Result<(), ContractError> {
+    if !is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.to_string()));
+    }
+    if let Some(thresholds) = thresholds {
+        validate_deviation_thresholds(thresholds)?;
+    }
+    Ok(())
+}
+
+// Validate a composite feed over supported components that do not depend on the token
+fn check_composite_feed(
+    deps: Deps,
+    token_id: &str,
+    feed: Option<&CompositeFeed>,
+) -> Result<(), ContractError> {
+    if !is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.to_string()));
+    }
+
+    if let Some(feed) = feed {
+        validate_composite_feed(feed)?;
+
+        for component in &feed.components {
+            if !is_token_supported(deps, &component.token_id)? {
+                return Err(ContractError::TokenNotSupported(component.token_id.clone()));
+            }
+            if feed_depends_on(deps.storage, &component.token_id, token_id)? {
+                return Err(ContractError::FeedCycle(token_id.to_string()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Drop a token's composite feed and its dependents index entries
+fn remove_composite_feed(
+    storage: &mut dyn Storage,
+    token_id: &str,
+) -> StdResult<Option<CompositeFeed>> {
+    let feed = COMPOSITE_FEEDS.may_load(storage, token_id)?;
+    if let Some(feed) = &feed {
+        COMPOSITE_FEEDS.remove(storage, token_id);
+        for component in &feed.components {
+            FEED_DEPENDENTS.remove(storage, (&component.token_id, token_id));
+        }
+    }
+    Ok(feed)
+}
+
+// Set or clear a token's derived feed - Only admin can call this
+fn execute_set_derived_feed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    feed: Option<DerivedFeed>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    check_derived_feed(deps.as_ref(), &token_id, feed.as_ref())?;
+
+    // A token is either a composite or a derived feed. Inputs for the old
+    // definition do not carry over, so the feed waits for a fresh submission.
+    remove_composite_feed(deps.storage, &token_id)?;
+    let old_feed = remove_derived_feed(deps.storage, &token_id)?;
+    if let Some(feed) = &feed {
+        DERIVED_FEEDS.save(deps.storage, &token_id, feed)?;
+        for source in feed.sources() {
+            FEED_DEPENDENTS.save(deps.storage, (source, &token_id), &Empty {})?;
+        }
+    }
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetDerivedFeed,
+        Some(&token_id),
+        old_feed.as_ref().map(to_json_string).transpose()?,
+        feed.as_ref().map(to_json_string).transpose()?,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_derived_feed")
+        .add_attribute("token_id", token_id))
+}
+
+// Validate a derived feed over supported sources that do not depend on the token
+fn check_derived_feed(
+    deps: Deps,
+    token_id: &str,
+    feed: Option<&DerivedFeed>,
+) -> Result<(), ContractError> {
+    if !is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.to_string()));
+    }
+
+    if let Some(feed) = feed {
+        if let DerivedFeed::LpShare { token_a, token_b } = feed {
+            if token_a == token_b {
+                return Err(ContractError::InvalidDerivedFeed(
+                    "LP tokens must be distinct".to_string(),
+                ));
+            }
+        }
+
+        for source in feed.sources() {
+            if !is_token_supported(deps, source)? {
+                return Err(ContractError::TokenNotSupported(source.to_string()));
+            }
+            if feed_depends_on(deps.storage, source, token_id)? {
+                return Err(ContractError::FeedCycle(token_id.to_string()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Check decimals can be set for a supported token
+fn check_token_decimals(
+    deps: Deps,
+    token_id: &str,
+    decimals: Option<u8>,
+) -> Result<(), ContractError> {
+    // Check if token is supported
+    if !is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.to_string()));
+    }
+
+    if decimals.is_some_and(|d| d > MAX_TOKEN_DECIMALS) {
+        return Err(ContractError::DecimalsTooLarge {
+            max: MAX_TOKEN_DECIMALS,
+        });
+    }
+    Ok(())
+}
+
+// Set token decimals used to scale prices to base units - Only admin can call this
+fn execute_set_token_decimals(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    decimals: Option<u8>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    check_token_decimals(deps.as_ref(), &token_id, decimals)?;
+
+    let mut token = TOKENS.load(deps.storage, &token_id)?;
+    let old_decimals = std::mem::replace(&mut token.decimals, decimals);
+    TOKENS.save(deps.storage, &token_id, &token)?;
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetTokenDecimals,
+        Some(&token_id),
+        old_decimals.map(|d| d.to_string()),
+        decimals.map(|d| d.to_string()),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_token_decimals")
+        .add_attribute("token_id", token_id)
+        .add_attribute(
+            "decimals",
+            decimals.map(|d| d.to_string()).unwrap_or_default(),
+        ))
+}
+
+// Set how first prices are accepted - Only admin can call this
+fn execute_set_bootstrap_policy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    policy: BootstrapPolicy,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    check_bootstrap_policy(&policy)?;
+
+    let old_policy = BOOTSTRAP_POLICY.may_load(deps.storage)?.unwrap_or_default();
+    BOOTSTRAP_POLICY.save(deps.storage, &policy)?;
+
+    // Submissions collected under a previous quorum no longer apply
+    if !matches!(policy, BootstrapPolicy::Quorum { .. }) {
+        BOOTSTRAP_SUBMISSIONS.clear(deps.storage);
+    }
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetBootstrapPolicy,
+        None,
+        Some(to_json_string(&old_policy)?),
+        Some(to_json_string(&policy)?),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_bootstrap_policy")
+        .add_attribute("policy", to_json_string(&policy)?))
+}
+
+// Check a quorum policy needs at least one submitter
+fn check_bootstrap_policy(policy: &BootstrapPolicy) -> Result<(), ContractError> {
+    if let BootstrapPolicy::Quorum {
+        submitters,
+        window_seconds,
+    } = policy
+    {
+        if *submitters == 0 {
+            return Err(ContractError::InvalidBootstrapPolicy(
+                "quorum needs at least one submitter".to_string(),
+            ));
+        }
+        if *window_seconds == 0 {
+            return Err(ContractError::InvalidBootstrapPolicy(
+                "quorum window must be greater than zero".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Set outlier filtering for rounds - Only admin can call this
+fn execute_set_aggregation_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: Option<AggregationConfig>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    check_aggregation_config(config.as_ref())?;
+
+    let old_config = AGGREGATION.may_load(deps.storage)?;
+    match &config {
+        Some(config) => AGGREGATION.save(deps.storage, config)?,
+        None => AGGREGATION.remove(deps.storage),
+    }
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetAggregationConfig,
+        None,
+        old_config.map(|c| to_json_string(&c)).transpose()?,
+        config.as_ref().map(to_json_string).transpose()?,
+    )?;
+
+    Ok(Response::new().add_attribute("method", "set_aggregation_config"))
+}
+
+// Validate outlier filtering for rounds
+fn check_aggregation_config(config: Option<&AggregationConfig>) -> Result<(), ContractError> {
+    let Some(config) = config else {
+        return Ok(());
+    };
+    if config.outlier_mads.is_zero() {
+        return Err(ContractError::InvalidAggregationConfig(
+            "outlier MADs must be greater than zero".to_string(),
+        ));
+    }
+    if config.min_survivors == 0 {
+        return Err(ContractError::InvalidAggregationConfig(
+            "at least one submission must survive".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// Drop a token's derived feed, its latest input and its dependents index entries
+fn remove_derived_feed(
+    storage: &mut dyn Storage,
+    token_id: &str,
+) -> StdResult<Option<DerivedFeed>> {
+    let feed = DERIVED_FEEDS.may_load(storage, token_id)?;
+    if let Some(feed) = &feed {
+        DERIVED_FEEDS.remove(storage, token_id);
+        DERIVED_INPUTS.remove(storage, token_id);
+        for source in feed.sources() {
+            FEED_DEPENDENTS.remove(storage, (source, token_id));
+        }
+    }
+    Ok(feed)
+}
+
+// Whether `token_id` is `target` or is computed, directly or not, from it
+fn feed_depends_on(storage: &dyn Storage, token_id: &str, target: &str) -> StdResult<bool> {
+    if token_id == target {
+        return Ok(true);
+    }
+
+    let sources: Vec<String> = match COMPOSITE_FEEDS.may_load(storage, token_id)? {
+        Some(feed) => feed.components.into_iter().map(|c| c.token_id).collect(),
+        None => DERIVED_FEEDS
+            .may_load(storage, token_id)?
+            .map(|feed| feed.sources().into_iter().map(String::from).collect())
+            .unwrap_or_default(),
+    };
+    for source in sources {
+        if feed_depends_on(storage, &source, target)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// Set the timelock delay - Only owner can call this
+fn execute_set_timelock_delay(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delay_seconds: u64,
+) -> Result<Response, ContractError> {
+    // Check if sender is the owner
+    if !is_owner(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    check_timelock_delay(delay_seconds)?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_delay = std::mem::replace(&mut config.timelock_delay, delay_seconds);
+    CONFIG.save(deps.storage, &config)?;
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetTimelockDelay,
+        None,
+        Some(old_delay.to_string()),
+        Some(delay_seconds.to_string()),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_timelock_delay")
+        .add_attribute("delay_seconds", delay_seconds.to_string()))
+}
+
+// Check a timelock delay is within the maximum
+fn check_timelock_delay(delay_seconds: u64) -> Result<(), ContractError> {
+    if delay_seconds > MAX_TIMELOCK_DELAY {
+        return Err(ContractError::TimelockDelayTooLong {
+            max_seconds: MAX_TIMELOCK_DELAY,
+        });
+    }
+    Ok(())
+}
+
+// Set or clear the guardian - Only owner can call this
+fn execute_set_guardian(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    guardian: Option<String>,
+) -> Result<Response, ContractError> {
+    // Check if sender is the owner
+    if !is_owner(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Validate guardian address
+    let guardian = guardian
+        .map(|guardian| deps.api.addr_validate(&guardian))
+        .transpose()?;
+
+    let mut config = CONFIG.load(deps.storage)?;
+    let old_guardian = std::mem::replace(&mut config.guardian, guardian.clone());
+    CONFIG.save(deps.storage, &config)?;
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetGuardian,
+        None,
+        old_guardian.map(|g| g.to_string()),
+        guardian.as_ref().map(|g| g.to_string()),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_guardian")
+        .add_attribute(
+            "guardian",
+            guardian.map(|g| g.to_string()).unwrap_or_default(),
+        ))
+}
+
+// Queue a timelocked change - Only the role the change requires can call this
+fn execute_queue_change(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    change: ExecuteMsg,
+    delay: u64,
+) -> Result<Response, ContractError> {
+    // Check the sender could make the change directly
+    let authorized = match change {
+        ExecuteMsg::SetTimelockDelay { .. } => is_owner(deps.as_ref(), &info.sender)?,
+        _ => is_admin(deps.as_ref(), &info.sender)?,
+    };
+    if !authorized {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Fail now rather than after the delay; the checks run again when the change executes
+    check_change(deps.as_ref(), &change)?;
+
+    let id = QUEUED_CHANGES_NEXT_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    QUEUED_CHANGES_NEXT_ID.save(deps.storage, &(id + 1))?;
+
+    let queued_at = env.block.time.seconds();
+    let execute_after = queued_at + delay;
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::QueueChange,
+        None,
+        None,
+        Some(to_json_string(&change)?),
+    )?;
+
+    QUEUED_CHANGES.save(
+        deps.storage,
+        id,
+        &QueuedChange {
+            id,
+            change,
+            proposer: info.sender.to_string(),
+            queued_at,
+            execute_after,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "queue_change")
+        .add_attribute("id", id.to_string())
+        .add_attribute("execute_after", execute_after.to_string()))
+}
+
+// Validation a timelocked change runs before it is applied, against the current state
+fn check_change(deps: Deps, change: &ExecuteMsg) -> Result<(), ContractError> {
+    match change {
+        ExecuteMsg::SetDeviationThreshold { threshold } => validate_threshold(*threshold),
+        ExecuteMsg::AddWhitelistedUpdater { updater } => {
+            check_add_updater(deps, updater).map(|_| ())
+        }
+        ExecuteMsg::SetUpdaterWeight { updater, weight } => {
+            check_updater_weight(deps, updater, *weight).map(|_| ())
+        }
+        ExecuteMsg::AddSupportedToken { token_id } => check_add_token(deps, token_id),
+        ExecuteMsg::RemoveSupportedToken { token_id } => check_remove_token(deps, token_id),
+        ExecuteMsg::SetEmaPeriods { half_lives } => {
+            check_ema_periods(deps, half_lives.clone()).map(|_| ())
+        }
+        ExecuteMsg::SetDeviationReference {
+            token_id,
+            reference,
+        } => check_deviation_reference(deps, token_id, reference),
+        ExecuteMsg::SetTokenDeviationThresholds {
+            token_id,
+            thresholds,
+        } => check_token_deviation_thresholds(deps, token_id, thresholds.as_ref()),
+        ExecuteMsg::SetTimelockDelay { delay_seconds } => check_timelock_delay(*delay_seconds),
+        ExecuteMsg::SetBondingConfig { config } => {
+            check_bonding_config(deps, config.clone()).map(|_| ())
+        }
+        ExecuteMsg::SetCompositeFeed { token_id, feed } => {
+            check_composite_feed(deps, token_id, feed.as_ref())
+        }
+        ExecuteMsg::SetDerivedFeed { token_id, feed } => {
+            check_derived_feed(deps, token_id, feed.as_ref())
+        }
+        ExecuteMsg::SetTokenDecimals { token_id, decimals } => {
+            check_token_decimals(deps, token_id, *decimals)
+        }
+        ExecuteMsg::SetBootstrapPolicy { policy } => check_bootstrap_policy(policy),
+        ExecuteMsg::SetAggregationConfig { config } => check_aggregation_config(config.as_ref()),
+        _ => Ok(()),
+    }
+}
+
+// Apply a queued change once its delay has passed - Anyone can call this
+fn execute_queued(deps: DepsMut, env: Env, id: u64) -> Result<Response, ContractError> {
+    let Some(queued) = QUEUED_CHANGES.may_load(deps.storage, id)? else {
+        return Err(ContractError::QueuedChangeNotFound(id));
+    };
+
+    // Check if the delay has passed
+    if env.block.time.seconds() < queued.execute_after {
+        return Err(ContractError::TimelockNotExpired {
+            id,
+            execute_after: queued.execute_after,
+        });
+    }
+
+    QUEUED_CHANGES.remove(deps.storage, id);
+
+    // Run the change as its proposer, so it fails if they lost their role
+    let info = MessageInfo {
+        sender: deps.api.addr_validate(&queued.proposer)?,
+        funds: vec![],
+    };
+    let response = dispatch(deps, env, info, queued.change)?;
+
+    Ok(response.add_attribute("queued_id", id.to_string()))
+}
+
+// Cancel a queued change - Only owner or guardian can call this
+fn execute_cancel_queued(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    // Check if sender is the owner or guardian
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner && config.guardian.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let Some(queued) = QUEUED_CHANGES.may_load(deps.storage, id)? else {
+        return Err(ContractError::QueuedChangeNotFound(id));
+    };
+    QUEUED_CHANGES.remove(deps.storage, id);
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::CancelQueuedChange,
+        None,
+        Some(to_json_string(&queued.change)?),
+        None,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "cancel_queued")
+        .add_attribute("id", id.to_string()))
+}
+
+// Set bonding requirements for updaters - Only admin can call this
+fn execute_set_bonding_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: Option<BondingConfig>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let config = check_bonding_config(deps.as_ref(), config)?;
+    let old_config = BONDING.may_load(deps.storage)?;
+    match &config {
+        Some(config) => BONDING.save(deps.storage, config)?,
+        None => BONDING.remove(deps.storage),
+    }
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetBondingConfig,
+        None,
+        old_config.map(|c| to_json_string(&c)).transpose()?,
+        config.as_ref().map(to_json_string).transpose()?,
+    )?;
+
+    let mut res = Response::new().add_attribute("method", "set_bonding_config");
+    if let Some(config) = config {
+        res = res
+            .add_attribute("denom", config.denom)
+            .add_attribute("min_bond", config.min_bond);
+    }
+    Ok(res)
+}
+
+// Validate a bonding config, returning it with the treasury address normalized
+fn check_bonding_config(
+    deps: Deps,
+    config: Option<BondingConfig>,
+) -> Result<Option<BondingConfig>, ContractError> {
+    let total_bonded = TOTAL_BONDED.may_load(deps.storage)?.unwrap_or_default();
+    let Some(config) = config else {
+        // Held bonds need the config to be unbonded and claimed
+        if !total_bonded.is_zero() {
+            return Err(ContractError::BondsOutstanding {});
+        }
+        return Ok(None);
+    };
+
+    if config.denom.is_empty() {
+        return Err(ContractError::InvalidBondingConfig(
+            "denom cannot be empty".to_string(),
+        ));
+    }
+    if config.rejection_slash > Decimal::one() {
+        return Err(ContractError::InvalidBondingConfig(
+            "rejection slash cannot exceed 100%".to_string(),
+        ));
+    }
+
+    // Validate treasury address
+    let treasury = deps.api.addr_validate(&config.treasury)?;
+    let config = BondingConfig {
+        treasury: treasury.to_string(),
+        ..config
+    };
+
+    // Bonds already held must stay claimable in their denom
+    if let Some(old_config) = BONDING.may_load(deps.storage)? {
+        if old_config.denom != config.denom && !total_bonded.is_zero() {
+            return Err(ContractError::BondDenomLocked {});
+        }
+    }
+
+    Ok(Some(config))
+}
+
+// Deposit a bond - Only whitelisted updaters can call this
+fn execute_bond(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let Some(bonding) = BONDING.may_load(deps.storage)? else {
+        return Err(ContractError::BondingDisabled {});
+    };
+
+    // Check if sender is whitelisted
+    if !WHITELISTED_UPDATERS.has(deps.storage, &info.sender) {
+        return Err(ContractError::UpdaterNotWhitelisted(
+            info.sender.to_string(),
+        ));
+    }
+
+    let amount = must_pay(&info, &bonding.denom)?;
+
+    let mut bond = BONDS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    bond.bonded += amount;
+    BONDS.save(deps.storage, &info.sender, &bond)?;
+    let total_bonded = TOTAL_BONDED.may_load(deps.storage)?.unwrap_or_default();
+    TOTAL_BONDED.save(deps.storage, &(total_bonded + amount))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "bond")
+        .add_attribute("updater", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("bonded", bond.bonded))
+}
+
+// Start unbonding part of the sender's bond
+fn execute_unbond(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let Some(bonding) = BONDING.may_load(deps.storage)? else {
+        return Err(ContractError::BondingDisabled {});
+    };
+
+    let mut bond = BONDS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    if amount.is_zero() || amount > bond.bonded {
+        return Err(ContractError::UnbondExceedsBond {
+            bonded: bond.bonded,
+        });
+    }
+
+    // Later unbonds restart the period for everything unbonding
+    bond.bonded -= amount;
+    bond.unbonding += amount;
+    bond.release_at = env.block.time.seconds() + bonding.unbonding_period;
+    BONDS.save(deps.storage, &info.sender, &bond)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "unbond")
+        .add_attribute("updater", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("release_at", bond.release_at.to_string()))
+}
+
+// Withdraw unbonded funds once the unbonding period has passed
+fn execute_claim_unbonded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let Some(bonding) = BONDING.may_load(deps.storage)? else {
+        return Err(ContractError::BondingDisabled {});
+    };
+
+    let Some(mut bond) = BONDS.may_load(deps.storage, &info.sender)? else {
+        return Err(ContractError::NothingToClaim {});
+    };
+    if bond.unbonding.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    if env.block.time.seconds() < bond.release_at {
+        return Err(ContractError::UnbondingNotComplete {
+            release_at: bond.release_at,
+        });
+    }
+    // Funds stay slashable until every deviating submission has been reviewed
+    if has_pending_updates(deps.storage, &info.sender) {
+        return Err(ContractError::PendingUpdatesUnresolved {});
+    }
+
+    let amount = std::mem::take(&mut bond.unbonding);
+    if bond.bonded.is_zero() {
+        BONDS.remove(deps.storage, &info.sender);
+    } else {
+        BONDS.save(deps.storage, &info.sender, &bond)?;
+    }
+    let total_bonded = TOTAL_BONDED.load(deps.storage)?;
+    TOTAL_BONDED.save(deps.storage, &(total_bonded - amount))?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(amount.u128(), bonding.denom),
+        })
+        .add_attribute("method", "claim_unbonded")
+        .add_attribute("updater", info.sender)
+        .add_attribute("amount", amount))
+}
+
+// Slash an updater's bond - Only admin can call this
+fn execute_slash(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    updater: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let Some(bonding) = BONDING.may_load(deps.storage)? else {
+        return Err(ContractError::BondingDisabled {});
+    };
+
+    // Validate updater address
+    let updater = deps.api.addr_validate(&updater)?;
+    let (slashed, msg) = slash(
+        deps.storage,
+        &env,
+        &info.sender,
+        &bonding,
+        &updater,
+        amount,
+        None,
+    )?;
+
+    Ok(Response::new()
+        .add_messages(msg)
+        .add_attribute("method", "slash")
+        .add_attribute("updater", updater)
+        .add_attribute("slashed", slashed))
+}
+
+// Take up to `amount` from an updater's bond, bonded funds first, and send it to the treasury
+fn slash(
+    storage: &mut dyn Storage,
+    env: &Env,
+    admin: &Addr,
+    bonding: &BondingConfig,
+    updater: &Addr,
+    amount: Uint128,
+    token_id: Option<&str>,
+) -> Result<(Uint128, Option<BankMsg>), ContractError> {
+    let Some(mut bond) = BONDS.may_load(storage, updater)? else {
+        return Ok((Uint128::zero(), None));
+    };
+
+    let from_bonded = amount.min(bond.bonded);
+    let from_unbonding = (amount - from_bonded).min(bond.unbonding);
+    let slashed = from_bonded + from_unbonding;
+    if slashed.is_zero() {
+        return Ok((slashed, None));
+    }
+
+    let old_bond = bond.bonded + bond.unbonding;
+    bond.bonded -= from_bonded;
+    bond.unbonding -= from_unbonding;
+    if bond.bonded.is_zero() && bond.unbonding.is_zero() {
+        BONDS.remove(storage, updater);
+    } else {
+        BONDS.save(storage, updater, &bond)?;
+    }
+    let total_bonded = TOTAL_BONDED.load(storage)?;
+    TOTAL_BONDED.save(storage, &(total_bonded - slashed))?;
+
+    record_audit(
+        storage,
+        env,
+        Some(admin),
+        AuditAction::Slash,
+        token_id,
+        Some(format!("{updater}: {old_bond}")),
+        Some(format!("{updater}: {}", old_bond - slashed)),
+    )?;
+
+    Ok((
+        slashed,
+        Some(BankMsg::Send {
+            to_address: bonding.treasury.clone(),
+            amount: coins(slashed.u128(), &bonding.denom),
+        }),
+    ))
+}
+
+// Set the updater reward rate - Only admin can call this
+fn execute_set_reward_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: RewardConfig,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if config.denom.is_empty() {
+        return Err(ContractError::InvalidRewardConfig(
+            "denom cannot be empty".to_string(),
+        ));
+    }
+    if config.per_submission.is_zero() {
+        return Err(ContractError::InvalidRewardConfig(
+            "reward per submission must be greater than zero".to_string(),
+        ));
+    }
+
+    // Pool funds and unclaimed rewards must stay claimable in their denom
+    let old_config = REWARDS.may_load(deps.storage)?;
+    if let Some(old_config) = &old_config {
+        let pool = REWARD_POOL.may_load(deps.storage)?.unwrap_or_default();
+        if old_config.denom != config.denom && !(pool.available + pool.accrued).is_zero() {
+            return Err(ContractError::RewardDenomLocked {});
+        }
+    }
+
+    REWARDS.save(deps.storage, &config)?;
+
+    record_audit(
+        deps.storage,
+        &env,
+        Some(&info.sender),
+        AuditAction::SetRewardConfig,
+        None,
+        old_config.map(|c| to_json_string(&c)).transpose()?,
+        Some(to_json_string(&config)?),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_reward_config")
+        .add_attribute("denom", config.denom)
+        .add_attribute("per_submission", config.per_submission))
+}
+
+// Add funds to the reward pool - Anyone can call this
+fn execute_fund_rewards(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let Some(rewards) = REWARDS.may_load(deps.storage)? else {
+        return Err(ContractError::RewardsDisabled {});
+    };
+
+    let amount = must_pay(&info, &rewards.denom)?;
+    let mut pool = REWARD_POOL.may_load(deps.storage)?.unwrap_or_default();
+    pool.available += amount;
+    REWARD_POOL.save(deps.storage, &pool)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "fund_rewards")
+        .add_attribute("funder", info.sender)
+        .add_attribute("amount", amount))
+}
+
+// Withdraw the sender's accrued rewards
+fn execute_claim_rewards(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let Some(rewards) = REWARDS.may_load(deps.storage)? else {
+        return Err(ContractError::RewardsDisabled {});
+    };
+
+    let amount = ACCRUED_REWARDS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    ACCRUED_REWARDS.remove(deps.storage, &info.sender);
+
+    let mut pool = REWARD_POOL.load(deps.storage)?;
+    pool.accrued -= amount;
+    REWARD_POOL.save(deps.storage, &pool)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(amount.u128(), rewards.denom),
+        })
+        .add_attribute("method", "claim_rewards")
+        .add_attribute("updater", info.sender)
+        .add_attribute("amount", amount))
+}
+
+// Credit an updater for a price accepted by the deviation check, as far as the pool allows.
+// The owner and admin write prices as part of their role and earn nothing.
+fn accrue_reward(storage: &mut dyn Storage, updater: &Addr) -> StdResult<()> {
+    let Some(rewards) = REWARDS.may_load(storage)? else {
+        return Ok(());
+    };
+
+    let config = CONFIG.load(storage)?;
+    if *updater == config.owner
+        || *updater == config.admin
+        || !WHITELISTED_UPDATERS.has(storage, updater)
+    {
+        return Ok(());
+    }
+
+    let mut pool = REWARD_POOL.may_load(storage)?.unwrap_or_default();
+    let reward = rewards.per_submission.min(pool.available);
+    if reward.is_zero() {
+        return Ok(());
+    }
+
+    pool.available -= reward;
+    pool.accrued += reward;
+    REWARD_POOL.save(storage, &pool)?;
+    ACCRUED_REWARDS.update(storage, updater, |accrued| -> StdResult<_> {
+        Ok(accrued.unwrap_or_default() + reward)
+    })?;
+
+    Ok(())
+}
+
+// Whether a price written now is newer than the token's stored one. Rewards are paid once
+// per token per block, so resubmitting a price cannot drain the pool.
+fn price_advances(storage: &dyn Storage, token_id: &str, current_time: u64) -> StdResult<bool> {
+    Ok(PRICES
+        .may_load(storage, token_id)?
+        .is_none_or(|price| price.last_updated < current_time))
+}
+
+// Amount of a single coin of the given denom sent with the message
+fn must_pay(info: &MessageInfo, denom: &str) -> Result<Uint128, ContractError> {
+    match info.funds.as_slice() {
+        [coin] if coin.denom == denom && !coin.amount.is_zero() => Ok(coin.amount),
+        _ => Err(ContractError::InvalidFunds(format!(
+            "expected a single {denom} coin"
+        ))),
+    }
+}
+
+// Outcome of checking a submitted price against the token's reference prices
+enum PriceCheck {
+    Accept {
+        deviation: Option<Decimal256>,
+    },
+    Pending {
+        current_price: Decimal256,
+        deviation: Decimal256,
+    },
+    Collect {}, // First price, counted toward the bootstrap quorum
+}
+
+// Compare a new price with the last stored price and, if configured, the token's
+// TWAP or EMA reference. Each comparison uses the token's directional, time-scaled
+// threshold, so a price walked in small steps still trips once it drifts from the average.
+fn check_price_deviation(
+    deps: Deps,
+    config: &Config,
+    token_id: &str,
+    new_price: Decimal256,
+    current_time: u64,
+) -> Result<PriceCheck, ContractError> {
+    let current_price = match PRICES.may_load(deps.storage, token_id)? {
+        Some(current_price) => current_price,
+        None => return Ok(PriceCheck::Accept { deviation: None }),
+    };
+
+    let token = TOKENS.load(deps.storage, token_id)?;
+    let thresholds = token.deviation_thresholds.unwrap_or(DeviationThresholds {
+        up: config.price_deviation_threshold,
+        down: config.price_deviation_threshold,
+        time_scaling: None,
+    });
+    let elapsed = current_time.saturating_sub(current_price.last_updated);
+
+    let reference_price = match token.deviation_reference {
+        Some(DeviationReference::Twap { window_seconds }) => {
+            calculate_twap(deps.storage, token_id, window_seconds, current_time)?
+        }
+        Some(DeviationReference::Ema { period }) => EMAS
+            .may_load(deps.storage, (token_id, period))?
+            .map(|ema| ema.value),
+        Some(DeviationReference::LastPrice {}) | None => None,
+    };
+
+    let mut max_deviation = Decimal256::zero();
+    let mut exceeded = false;
+    for reference in std::iter::once(current_price.usd).chain(reference_price) {
+        let deviation = calculate_price_deviation(reference, new_price)?;
+        let threshold = deviation_threshold(&thresholds, reference, new_price, elapsed);
+
+        max_deviation = max_deviation.max(deviation);
+        exceeded |= deviation > Decimal256::from(threshold);
+    }
+
+    if exceeded {
+        Ok(PriceCheck::Pending {
+            current_price: current_price.usd,
+            deviation: max_deviation,
+        })
+    } else {
+        Ok(PriceCheck::Accept {
+            deviation: Some(max_deviation),
+        })
+    }
+}
+
+// Sender checks shared by price submissions and their simulation
+fn check_updater(deps: Deps, config: &Config, sender: &Addr) -> Result<(), ContractError> {
+    // Check if sender is whitelisted
+    if !is_whitelisted(deps, sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Check if the updater has bonded enough, when bonding is enabled
+    if !is_admin(deps, sender)? {
+        if let Some(bonding) = BONDING.may_load(deps.storage)? {
+            let bonded = BONDS
+                .may_load(deps.storage, sender)?
+                .unwrap_or_default()
+                .bonded;
+            if bonded < bonding.min_bond {
+                return Err(ContractError::InsufficientBond {
+                    required: bonding.min_bond,
+                    bonded,
+                });
+            }
+        }
+    }
+
+    // Check if price writes are paused
+    if config.paused {
+        return Err(ContractError::Paused {});
+    }
+
+    Ok(())
+}
+
+// Per-token checks shared by price submissions and their simulation
+fn check_price_submission(
+    deps: Deps,
+    config: &Config,
+    sender: &Addr,
+    token_id: &str,
+    new_price: Decimal256,
+    current_time: u64,
+) -> Result<PriceCheck, ContractError> {
+    // Check if token is supported
+    if !is_token_supported(deps, token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.to_string()));
+    }
+
+    // Derived feeds are only written from their components
+    if COMPOSITE_FEEDS.has(deps.storage, token_id) || DERIVED_FEEDS.has(deps.storage, token_id) {
+        return Err(ContractError::DerivedFeed(token_id.to_string()));
+    }
+
+    check_submitted_price(deps, config, sender, token_id, new_price, current_time)
+}
+
+// Bootstrap and deviation checks for a price submitted directly or derived from an input
+fn check_submitted_price(
+    deps: Deps,
+    config: &Config,
+    sender: &Addr,
+    token_id: &str,
+    new_price: Decimal256,
+    current_time: u64,
+) -> Result<PriceCheck, ContractError> {
+    check_price_value(token_id, new_price)?;
+
+    // The first price has nothing to deviate from, so the bootstrap policy decides
+    if !PRICES.has(deps.storage, token_id) {
+        match BOOTSTRAP_POLICY.may_load(deps.storage)?.unwrap_or_default() {
+            BootstrapPolicy::Trusted {} => {}
+            BootstrapPolicy::AdminOnly {} => {
+                if !is_admin(deps, sender)? {
+                    return Ok(PriceCheck::Pending {
+                        current_price: Decimal256::zero(),
+                        deviation: Decimal256::one(),
+                    });
+                }
+            }
+            BootstrapPolicy::Quorum { .. } => return Ok(PriceCheck::Collect {}),
+        }
+    }
+
+    check_price_deviation(deps, config, token_id, new_price, current_time)
+}
+
+// Reject prices no feed can hold; every later deviation would be measured against them
+fn check_price_value(token_id: &str, price: Decimal256) -> Result<(), ContractError> {
+    if price.is_zero() {
+        return Err(ContractError::ZeroPrice(token_id.to_string()));
+    }
+    Ok(())
+}
+
+// Record a first-price submission and, once the quorum is reached, write the round's
+// aggregate price. Returns whether the price was written.
+fn collect_bootstrap_submission(
+    storage: &mut dyn Storage,
+    sender: &Addr,
+    token_id: &str,
+    price: Decimal256,
+    current_time: u64,
+) -> Result<bool, ContractError> {
+    let Some(BootstrapPolicy::Quorum {
+        submitters,
+        window_seconds,
+    }) = BOOTSTRAP_POLICY.may_load(storage)?
+    else {
+        return Ok(false);
+    };
+
+    BOOTSTRAP_SUBMISSIONS.save(
+        storage,
+        (token_id, sender),
+        &BootstrapSubmission {
+            price,
+            submitted_at: current_time,
+        },
+    )?;
+    let collected: Vec<(Addr, BootstrapSubmission)> = BOOTSTRAP_SUBMISSIONS
+        .prefix(token_id)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    let mut submissions = vec![];
+    for (updater, submission) in collected {
+        // Submissions from before the window are dropped, so a round never mixes in stale prices
+        if current_time.saturating_sub(submission.submitted_at) > window_seconds {
+            BOOTSTRAP_SUBMISSIONS.remove(storage, (token_id, &updater));
+            continue;
+        }
+
+        // Submissions from updaters since removed from the whitelist no longer count
+        let weight = updater_weight(storage, &updater)?;
+        if !weight.is_zero() {
+            submissions.push(Submission {
+                updater,
+                price: submission.price,
+                weight,
+            });
+        }
+    }
+    if submissions.len() < submitters as usize {
+        return Ok(false);
+    }
+
+    let aggregate = match AGGREGATION.may_load(storage)? {
+        Some(config) => {
+            mad_filtered_aggregate(&submissions, config.outlier_mads, config.min_survivors)
+        }
+        None => median_aggregate(&submissions),
+    };
+    let aggregate = match aggregate {
+        Ok(aggregate) => aggregate,
+        // Keep collecting until enough submissions agree
+        Err(ContractError::InsufficientSubmissions { .. }) => return Ok(false),
+        Err(err) => return Err(err),
+    };
+
+    for outlier in &aggregate.outliers {
+        UPDATER_STATS.update(storage, outlier, |stats| -> StdResult<_> {
+            let mut stats = stats.unwrap_or_default();
+            stats.outlier_count += 1;
+            Ok(stats)
+        })?;
+    }
+    write_price(
+        storage,
+        token_id,
+        aggregate.price,
+        current_time,
+        Submitter::Quorum {
+            updaters: aggregate.survivors.iter().map(Addr::to_string).collect(),
+            outliers: aggregate.outliers.iter().map(Addr::to_string).collect(),
+        },
+    )?;
+
+    Ok(true)
+}
+
+// Drop first-price submissions collected for a token
+fn clear_bootstrap_submissions(storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+    let updaters: Vec<Addr> = BOOTSTRAP_SUBMISSIONS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for updater in updaters {
+        BOOTSTRAP_SUBMISSIONS.remove(storage, (token_id, &updater));
+    }
+    Ok(())
+}
+
+// Store a new price, append it to history, fold it into every configured EMA
+// and reprice feeds derived from the token
+pub(crate) fn write_price(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    price: Decimal256,
+    current_time: u64,
+    submitter: Submitter,
+) -> StdResult<()> {
+    let token_price = TokenPrice {
+        usd: price,
+        last_updated: current_time,
+        submitter: submitter.clone(),
+    };
+
+    if !PRICES.has(storage, token_id) {
+        clear_bootstrap_submissions(storage, token_id)?;
+    }
+    PRICES.save(storage, token_id, &token_price)?;
+    PRICE_HISTORY.save(
+        storage,
+        (token_id, current_time),
+        &PriceRecord { price, submitter },
+    )?;
+
+    for half_life in EMA_PERIODS.may_load(storage)?.unwrap_or_default() {
+        let value = match EMAS.may_load(storage, (token_id, half_life))? {
+            Some(ema) => {
+                let decay =
+                    half_life_decay(current_time.saturating_sub(ema.last_updated), half_life);
+                let decay = Decimal256::from(decay);
+                ema.value * decay + price * (Decimal256::one() - decay)
+            }
+            None => price,
+        };
+
+        EMAS.save(
+            storage,
+            (token_id, half_life),
+            &EmaValue {
+                value,
+                last_updated: current_time,
+            },
+        )?;
+    }
+
+    update_dependents(storage, token_id, current_time)
+}
+
+// Reprice every composite and derived feed built on the token
+fn update_dependents(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    current_time: u64,
+) -> StdResult<()> {
+    let dependents: Vec<String> = FEED_DEPENDENTS
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    for dependent in dependents {
+        // A price rounded down to zero leaves the last price in place
+        if let Some(price) = feed_price(storage, &dependent)?.filter(|p| !p.is_zero()) {
+            write_price(
+                storage,
+                &dependent,
+                price,
+                current_time,
+                Submitter::Derived {},
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+// Current price of a composite or derived feed, or None while an input is missing
+fn feed_price(storage: &dyn Storage, token_id: &str) -> StdResult<Option<Decimal256>> {
+    if let Some(feed) = COMPOSITE_FEEDS.may_load(storage, token_id)? {
+        return composite_price(storage, &feed);
+    }
+
+    let (Some(feed), Some(input)) = (
+        DERIVED_FEEDS.may_load(storage, token_id)?,
+        DERIVED_INPUTS.may_load(storage, token_id)?,
+    ) else {
+        return Ok(None);
+    };
+    derived_price(storage, token_id, &feed, &input)
+}
+
+// Price of a derived feed from its input and source prices, or None while a source has no price
+fn derived_price(
+    storage: &dyn Storage,
+    token_id: &str,
+    feed: &DerivedFeed,
+    input: &DerivedInput,
+) -> StdResult<Option<Decimal256>> {
+    let mut prices = Vec::new();
+    for source in feed.sources() {
+        match PRICES.may_load(storage, source)? {
+            Some(price) => prices.push(price.usd),
+            None => return Ok(None),
+        }
+    }
+
+    match (feed, input) {
+        (DerivedFeed::StakingDerivative { .. }, DerivedInput::ExchangeRate { rate }) => {
+            Ok(Some(prices[0].checked_mul(Decimal256::from(*rate))?))
+        }
+        (
+            DerivedFeed::LpShare { token_a, token_b },
+            DerivedInput::Reserves {
+                reserve_a,
+                reserve_b,
+                total_supply,
+            },
+        ) => {
+            // Reserves and supply are submitted in base units
+            let decimals = |token_id: &str| -> StdResult<Option<u8>> {
+                Ok(TOKENS.load(storage, token_id)?.decimals)
+            };
+            fair_lp_price(
+                whole_tokens(*reserve_a, decimals(token_a)?)?,
+                whole_tokens(*reserve_b, decimals(token_b)?)?,
+                whole_tokens(*total_supply, decimals(token_id)?)?,
+                prices[0],
+                prices[1],
+            )
+            .map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+// Weighted sum of component prices over the divisor, or None while a component has no price
+fn composite_price(storage: &dyn Storage, feed: &CompositeFeed) -> StdResult<Option<Decimal256>> {
+    let mut total = Decimal256::zero();
+    for component in &feed.components {
+        let Some(price) = PRICES.may_load(storage, &component.token_id)? else {
+            return Ok(None);
+        };
+        total = total.checked_add(price.usd.checked_mul(Decimal256::from(component.weight))?)?;
+    }
+
+    match feed.divisor {
+        Some(divisor) => total
+            .checked_div(Decimal256::from(divisor))
+            .map(Some)
+            .map_err(|e| StdError::generic_err(e.to_string())),
+        None => Ok(Some(total)),
+    }
+}
+
+// Keep a resolved pending update for later review
+fn resolve_update(
+    storage: &mut dyn Storage,
+    env: &Env,
+    actor: Option<&Addr>,
+    update: PendingUpdate,
+    resolution: Resolution,
+) -> StdResult<()> {
+    let id = RESOLVED_UPDATES_NEXT_ID
+        .may_load(storage)?
+        .unwrap_or_default();
+    RESOLVED_UPDATES_NEXT_ID.save(storage, &(id + 1))?;
+
+    let token_id = update.token_id.clone();
+    RESOLVED_UPDATES.save(
+        storage,
+        (token_id.as_str(), id),
+        &ResolvedUpdate {
+            id,
+            update,
+            resolution,
+            resolved_by: actor.map(|a| a.to_string()),
+            resolved_at: env.block.time.seconds(),
+        },
+    )
+}
+
+fn next_pending_update_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let id = PENDING_UPDATES_NEXT_ID
+        .may_load(storage)?
+        .unwrap_or_default();
+    PENDING_UPDATES_NEXT_ID.save(storage, &(id + 1))?;
+    Ok(id)
+}
+
+// IDs of the pending updates queued for a token, oldest first
+fn pending_update_ids(storage: &dyn Storage, token_id: &str) -> StdResult<Vec<u64>> {
+    PENDING_UPDATES_BY_TOKEN
+        .prefix(token_id)
+        .keys(storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Index a pending update under its token and, for updater submissions, its updater
+fn save_pending_update(storage: &mut dyn Storage, update: &PendingUpdate) -> StdResult<()> {
+    PENDING_UPDATES.save(storage, update.id, update)?;
+    PENDING_UPDATES_BY_TOKEN.save(storage, (&update.token_id, update.id), &Empty {})?;
+    if let Submitter::Updater { address } = &update.submitter {
+        PENDING_UPDATES_BY_UPDATER.save(storage, (address, update.id), &Empty {})?;
+    }
+    Ok(())
+}
+
+fn remove_pending_update(storage: &mut dyn Storage, update: &PendingUpdate) {
+    PENDING_UPDATES.remove(storage, update.id);
+    PENDING_UPDATES_BY_TOKEN.remove(storage, (&update.token_id, update.id));
+    PENDING_DERIVED_INPUTS.remove(storage, update.id);
+    if let Submitter::Updater { address } = &update.submitter {
+        PENDING_UPDATES_BY_UPDATER.remove(storage, (address, update.id));
+    }
+}
+
+// Whether an updater still has submissions awaiting review
+fn has_pending_updates(storage: &dyn Storage, updater: &Addr) -> bool {
+    PENDING_UPDATES_BY_UPDATER
+        .prefix(updater.as_str())
+        .keys(storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+}
+
+fn supersede_pending_update(
+    storage: &mut dyn Storage,
+    env: &Env,
+    actor: Option<&Addr>,
+    id: u64,
+    by: Option<u64>,
+) -> StdResult<()> {
+    let update = PENDING_UPDATES.load(storage, id)?;
+    remove_pending_update(storage, &update);
+    resolve_update(storage, env, actor, update, Resolution::Superseded { by })
+}
+
+// Queue a deviating submission. It replaces the submitter's earlier updates for the
+// token, and the oldest updates are superseded once the queue is full.
+fn queue_pending_update(
+    storage: &mut dyn Storage,
+    env: &Env,
+    submitter: &Addr,
+    update: PendingUpdate,
+) -> StdResult<()> {
+    let mut queued = vec![];
+    for id in pending_update_ids(storage, &update.token_id)? {
+        if PENDING_UPDATES.load(storage, id)?.submitter == update.submitter {
+            supersede_pending_update(storage, env, Some(submitter), id, Some(update.id))?;
+        } else {
+            queued.push(id);
+        }
+    }
+
+    let excess = (queued.len() + 1).saturating_sub(MAX_PENDING_UPDATES_PER_TOKEN);
+    for id in queued.into_iter().take(excess) {
+        supersede_pending_update(storage, env, Some(submitter), id, Some(update.id))?;
+    }
+
+    save_pending_update(storage, &update)
+}
+
+// Append a privileged action to the audit log
+fn record_audit(
+    storage: &mut dyn Storage,
+    env: &Env,
+    actor: Option<&Addr>,
+    action: AuditAction,
+    token_id: Option<&str>,
+    old_value: Option<String>,
+    new_value: Option<String>,
+) -> StdResult<()> {
+    let id = AUDIT_LOG_NEXT_ID.may_load(storage)?.unwrap_or_default();
+    AUDIT_LOG_NEXT_ID.save(storage, &(id + 1))?;
+
+    AUDIT_LOG.save(
+        storage,
+        id,
+        &AuditEntry {
+            id,
+            actor: actor.map(|a| a.to_string()),
+            action,
+            token_id: token_id.map(|t| t.to_string()),
+            old_value,
+            new_value,
+            height: env.block.height,
+            timestamp: env.block.time.seconds(),
+        },
+    )?;
+    AUDIT_LOG_BY_ACTION.save(storage, (action.as_str(), id), &Empty {})
+}
+
+// Track who submitted what and when, so silent updaters can be detected
+fn record_submission(
+    storage: &mut dyn Storage,
+    updater: &Addr,
+    token_id: &str,
+    pending: bool,
+    current_time: u64,
+) -> StdResult<()> {
+    UPDATER_STATS.update(storage, updater, |stats| -> StdResult<_> {
+        let mut stats = stats.unwrap_or_default();
+        stats.last_submission = current_time;
+        stats.submission_count += 1;
+        if pending {
+            stats.pending_count += 1;
+        }
+        if let Err(index) = stats
+            .tokens_covered
+            .binary_search_by(|t| t.as_str().cmp(token_id))
+        {
+            stats.tokens_covered.insert(index, token_id.to_string());
+        }
+        Ok(stats)
+    })?;
+    Ok(())
+}
+
+// Update prices - Only whitelisted updaters can call this
+fn execute_update_prices(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    price_data: crate::msg::PriceData,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    check_updater(deps.as_ref(), &config, &info.sender)?;
+
+    let mut response = Response::new().add_attribute("method", "update_prices");
+    let current_time = env.block.time.seconds();
+
+    // Process each price update
+    for (token_id, price_info) in price_data.prices {
+        let check = check_price_submission(
+            deps.as_ref(),
+            &config,
+            &info.sender,
+            &token_id,
+            price_info.usd,
+            current_time,
+        )?;
+
+        // First prices under a quorum policy wait for enough submitters
+        if let PriceCheck::Collect {} = check {
+            let written = collect_bootstrap_submission(
+                deps.storage,
+                &info.sender,
+                &token_id,
+                price_info.usd,
+                current_time,
+            )?;
+            record_submission(deps.storage, &info.sender, &token_id, false, current_time)?;
+
+            let status = if written {
+                "token_updated"
+            } else {
+                "token_collecting"
+            };
+            response = response.add_attribute(status, &token_id);
+            continue;
+        }
+
+        // If price deviation exceeds threshold, add to pending updates
+        if let PriceCheck::Pending {
+            current_price,
+            deviation,
+        } = check
+        {
+            let pending_update = PendingUpdate {
+                id: next_pending_update_id(deps.storage)?,
+                token_id: token_id.clone(),
+                current_price,
+                new_price: price_info.usd,
+                percent_change: deviation,
+                requested_at: current_time,
+                submitter: Submitter::Updater {
+                    address: info.sender.to_string(),
+                },
+            };
+
+            queue_pending_update(deps.storage, &env, &info.sender, pending_update)?;
+            record_submission(deps.storage, &info.sender, &token_id, true, current_time)?;
+
+            response = response.add_attribute("token_pending", &token_id);
+            continue;
+        }
+
+        // Update price and add to history
+        let rewarded = price_advances(deps.storage, &token_id, current_time)?;
+        write_price(
+            deps.storage,
+            &token_id,
+            price_info.usd,
+            current_time,
+            Submitter::Updater {
+                address: info.sender.to_string(),
+            },
+        )?;
+        if rewarded {
+            accrue_reward(deps.storage, &info.sender)?;
+        }
+        record_submission(deps.storage, &info.sender, &token_id, false, current_time)?;
+
+        response = response.add_attribute("token_updated", &token_id);
+    }
+
+    Ok(response)
+}
+
+// Update single price - Only whitelisted updaters can call this
+fn execute_update_single_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    price_info: crate::msg::TokenPriceInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    check_updater(deps.as_ref(), &config, &info.sender)?;
+
+    let current_time = env.block.time.seconds();
+
+    let check = check_price_submission(
+        deps.as_ref(),
+        &config,
+        &info.sender,
+        &token_id,
+        price_info.usd,
+        current_time,
+    )?;
+
+    // First prices under a quorum policy wait for enough submitters
+    if let PriceCheck::Collect {} = check {
+        let written = collect_bootstrap_submission(
+            deps.storage,
+            &info.sender,
+            &token_id,
+            price_info.usd,
+            current_time,
+        )?;
+        record_submission(deps.storage, &info.sender, &token_id, false, current_time)?;
+
+        return Ok(Response::new()
+            .add_attribute("method", "update_single_price")
+            .add_attribute("token_id", token_id)
+            .add_attribute("status", if written { "updated" } else { "collecting" }));
+    }
+
+    // If price deviation exceeds threshold, add to pending updates
+    if let PriceCheck::Pending {
+        current_price,
+        deviation,
+    } = check
+    {
+        let pending_update = PendingUpdate {
+            id: next_pending_update_id(deps.storage)?,
+            token_id: token_id.clone(),
+            current_price,
+            new_price: price_info.usd,
+            percent_change: deviation,
+            requested_at: current_time,
+            submitter: Submitter::Updater {
+                address: info.sender.to_string(),
+            },
+        };
+        let pending_id = pending_update.id;
+
+        queue_pending_update(deps.storage, &env, &info.sender, pending_update)?;
+        record_submission(deps.storage, &info.sender, &token_id, true, current_time)?;
+
+        return Ok(Response::new()
+            .add_attribute("method", "update_single_price")
+            .add_attribute("token_id", token_id)
+            .add_attribute("pending_id", pending_id.to_string())
+            .add_attribute("status", "pending_approval")
+            .add_attribute("deviation", deviation.to_string()));
+    }
+
+    // Update price and add to history
+    let rewarded = price_advances(deps.storage, &token_id, current_time)?;
+    write_price(
+        deps.storage,
+        &token_id,
+        price_info.usd,
+        current_time,
+        Submitter::Updater {
+            address: info.sender.to_string(),
+        },
+    )?;
+    if rewarded {
+        accrue_reward(deps.storage, &info.sender)?;
+    }
+    record_submission(deps.storage, &info.sender, &token_id, false, current_time)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_single_price")
+        .add_attribute("token_id", token_id)
+        .add_attribute("price", price_info.usd.to_string())
+        .add_attribute("status", "updated"))
+}
+
+// Submit the rate or reserves a derived feed is priced from - Only whitelisted updaters can call this
+fn execute_update_derived_input(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    input: DerivedInput,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    check_updater(deps.as_ref(), &config, &info.sender)?;
+
+    let Some(feed) = DERIVED_FEEDS.may_load(deps.storage, &token_id)? else {
+        return Err(ContractError::InvalidDerivedInput(format!(
+            "token {} is not a derived feed",
+            token_id
+        )));
+    };
+
+    match (&feed, &input) {
+        (DerivedFeed::StakingDerivative { .. }, DerivedInput::ExchangeRate { rate }) => {
+            if rate.is_zero() {
+                return Err(ContractError::InvalidDerivedInput(
+                    "exchange rate must be greater than zero".to_string(),
+                ));
+            }
+        }
+        (
+            DerivedFeed::LpShare { .. },
+            DerivedInput::Reserves {
+                reserve_a,
+                reserve_b,
+                total_supply,
+            },
+        ) => {
+            if total_supply.is_zero() {
+                return Err(ContractError::InvalidDerivedInput(
+                    "total supply must be greater than zero".to_string(),
+                ));
+            }
+            if reserve_a.is_zero() || reserve_b.is_zero() {
+                return Err(ContractError::InvalidDerivedInput(
+                    "reserves must be greater than zero".to_string(),
+                ));
+            }
+        }
+        _ => {
+            return Err(ContractError::InvalidDerivedInput(format!(
+                "input does not match the feed of token {}",
+                token_id
+            )))
+        }
+    }
+
+    let current_time = env.block.time.seconds();
+    let Some(price) = derived_price(deps.storage, &token_id, &feed, &input)? else {
+        // Priced once every source has a price
+        DERIVED_INPUTS.save(deps.storage, &token_id, &input)?;
+        return Ok(Response::new()
+            .add_attribute("method", "update_derived_input")
+            .add_attribute("token_id", token_id)
+            .add_attribute("status", "awaiting_sources"));
+    };
+
+    // The derived price is checked like a submitted one. The input only becomes the
+    // feed's input once its price is written, so source updates never reprice from an
+    // input still under review.
+    let check = check_submitted_price(
+        deps.as_ref(),
+        &config,
+        &info.sender,
+        &token_id,
+        price,
+        current_time,
+    )?;
+
+    // First prices under a quorum policy wait for enough submitters
+    if let PriceCheck::Collect {} = check {
+        let written = collect_bootstrap_submission(
+            deps.storage,
+            &info.sender,
+            &token_id,
+            price,
+            current_time,
+        )?;
+        if written {
+            DERIVED_INPUTS.save(deps.storage, &token_id, &input)?;
+        }
+        record_submission(deps.storage, &info.sender, &token_id, false, current_time)?;
+
+        return Ok(Response::new()
+            .add_attribute("method", "update_derived_input")
+            .add_attribute("token_id", token_id)
+            .add_attribute("price", price.to_string())
+            .add_attribute("status", if written { "updated" } else { "collecting" }));
+    }
+
+    // If price deviation exceeds threshold, add to pending updates with its input
+    if let PriceCheck::Pending {
+        current_price,
+        deviation,
+    } = check
+    {
+        let pending_update = PendingUpdate {
+            id: next_pending_update_id(deps.storage)?,
+            token_id: token_id.clone(),
+            current_price,
+            new_price: price,
+            percent_change: deviation,
+            requested_at: current_time,
+            submitter: Submitter::Updater {
+                address: info.sender.to_string(),
+            },
+        };
+        let pending_id = pending_update.id;
+
+        queue_pending_update(deps.storage, &env, &info.sender, pending_update)?;
+        PENDING_DERIVED_INPUTS.save(deps.storage, pending_id, &input)?;
+        record_submission(deps.storage, &info.sender, &token_id, true, current_time)?;
+
+        return Ok(Response::new()
+            .add_attribute("method", "update_derived_input")
+            .add_attribute("token_id", token_id)
+            .add_attribute("pending_id", pending_id.to_string())
+            .add_attribute("status", "pending_approval")
+            .add_attribute("deviation", deviation.to_string()));
+    }
+
+    DERIVED_INPUTS.save(deps.storage, &token_id, &input)?;
+    let rewarded = price_advances(deps.storage, &token_id, current_time)?;
+    write_price(
+        deps.storage,
+        &token_id,
+        price,
+        current_time,
+        Submitter::Updater {
+            address: info.sender.to_string(),
+        },
+    )?;
+    if rewarded {
+        accrue_reward(deps.storage, &info.sender)?;
+    }
+    record_submission(deps.storage, &info.sender, &token_id, false, current_time)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_derived_input")
+        .add_attribute("token_id", token_id)
+        .add_attribute("price", price.to_string())
+        .add_attribute("status", "updated"))
+}
+
+// Approve pending price - Only admin can call this
+fn execute_approve_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    price: Decimal256,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Check if price writes are paused
+    if CONFIG.load(deps.storage)?.paused {
+        return Err(ContractError::Paused {});
+    }
+
+    // Check if token is supported
+    if !is_token_supported(deps.as_ref(), &token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id.clone()));
+    }
+
+    // Check if there's a pending update, approving the newest one
+    let Some(id) = pending_update_ids(deps.storage, &token_id)?.pop() else {
+        return Err(ContractError::NoPendingUpdate(token_id));
+    };
+    let pending_update = PENDING_UPDATES.load(deps.storage, id)?;
+
+    approve_pending_update(deps, &env, &info.sender, pending_update, price)
+}
+
+// Approve a pending update by ID - Only admin can call this
+fn execute_approve_pending_update(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    price: Option<Decimal256>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Check if price writes are paused
+    if CONFIG.load(deps.storage)?.paused {
+        return Err(ContractError::Paused {});
+    }
+
+    // Check if the pending update exists
+    let Some(pending_update) = PENDING_UPDATES.may_load(deps.storage, id)? else {
+        return Err(ContractError::PendingUpdateNotFound(id));
+    };
+
+    let price = price.unwrap_or(pending_update.new_price);
+    approve_pending_update(deps, &env, &info.sender, pending_update, price)
+}
+
+// Write an approved price, then resolve the update and any older ones for the token
+fn approve_pending_update(
+    deps: DepsMut,
+    env: &Env,
+    admin: &Addr,
+    pending_update: PendingUpdate,
+    price: Decimal256,
+) -> Result<Response, ContractError> {
+    let token_id = pending_update.token_id.clone();
+    let id = pending_update.id;
+    check_price_value(&token_id, price)?;
+
+    // An approved derived price brings the input it was computed from
+    if let Some(input) = PENDING_DERIVED_INPUTS.may_load(deps.storage, id)? {
+        DERIVED_INPUTS.save(deps.storage, &token_id, &input)?;
+    }
+
+    // Update price and add to history
+    write_price(
+        deps.storage,
+        &token_id,
+        price,
+        env.block.time.seconds(),
+        Submitter::ApprovedBy {
+            admin: admin.to_string(),
+        },
+    )?;
+
+    record_audit(
+        deps.storage,
+        env,
+        Some(admin),
+        AuditAction::ApprovePrice,
+        Some(&token_id),
+        Some(pending_update.current_price.to_string()),
+        Some(price.to_string()),
+    )?;
+
+    // Move pending update to the resolved history
+    remove_pending_update(deps.storage, &pending_update);
+    resolve_update(
+        deps.storage,
+        env,
+        Some(admin),
+        pending_update,
+        Resolution::Approved { price },
+    )?;
+
+    // Older updates were measured against a price that is no longer current
+    for older in pending_update_ids(deps.storage, &token_id)? {
+        if older < id {
+            supersede_pending_update(deps.storage, env, Some(admin), older, Some(id))?;
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "approve_price")
+        .add_attribute("token_id", token_id)
+        .add_attribute("pending_id", id.to_string())
+        .add_attribute("price", price.to_string()))
+}
+
+// Reject pending price - Only admin can call this
+fn execute_reject_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_rejection_reason(&reason)?;
+
+    // Check if there's a pending update, rejecting the newest one
+    let Some(id) = pending_update_ids(deps.storage, &token_id)?.pop() else {
+        return Err(ContractError::NoPendingUpdate(token_id));
+    };
+    let pending_update = PENDING_UPDATES.load(deps.storage, id)?;
+
+    reject_pending_update(deps, &env, &info.sender, pending_update, reason)
+}
+
+// Reject a pending update by ID - Only admin can call this
+fn execute_reject_pending_update(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    // Check if sender is admin or owner
+    if !is_admin(deps.as_ref(), &info.sender)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    validate_rejection_reason(&reason)?;
+
+    // Check if the pending update exists
+    let Some(pending_update) = PENDING_UPDATES.may_load(deps.storage, id)? else {
+        return Err(ContractError::PendingUpdateNotFound(id));
+    };
+
+    reject_pending_update(deps, &env, &info.sender, pending_update, reason)
+}
+
+// Drop a pending update, keeping it in the resolved history
+fn reject_pending_update(
+    deps: DepsMut,
+    env: &Env,
+    admin: &Addr,
+    pending_update: PendingUpdate,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    let token_id = pending_update.token_id.clone();
+    let id = pending_update.id;
+
+    // The new value is the rejected submission
+    record_audit(
+        deps.storage,
+        env,
+        Some(admin),
+        AuditAction::RejectPrice,
+        Some(&token_id),
+        Some(pending_update.current_price.to_string()),
+        Some(pending_update.new_price.to_string()),
+    )?;
+
+    // Slash the submitting updater's bond, when bonding is enabled
+    let mut slash_msg = None;
+    if let (Some(bonding), Submitter::Updater { address }) =
+        (BONDING.may_load(deps.storage)?, &pending_update.submitter)
+    {
+        let updater = deps.api.addr_validate(address)?;
+        let bond = BONDS.may_load(deps.storage, &updater)?.unwrap_or_default();
+        let amount = (bond.bonded + bond.unbonding).mul_floor(bonding.rejection_slash);
+        (_, slash_msg) = slash(
+            deps.storage,
+            env,
+            admin,
+            &bonding,
+            &updater,
+            amount,
+            Some(&token_id),
+        )?;
+    }
+
+    // Move pending update to the resolved history
+    remove_pending_update(deps.storage, &pending_update);
+    resolve_update(
+        deps.storage,
+        env,
+        Some(admin),
+        pending_update,
+        Resolution::Rejected { reason },
+    )?;
+
+    Ok(Response::new()
+        .add_messages(slash_msg)
+        .add_attribute("method", "reject_price")
+        .add_attribute("token_id", token_id)
+        .add_attribute("pending_id", id.to_string()))
+}
+
+fn validate_rejection_reason(reason: &Option<String>) -> Result<(), ContractError> {
+    if reason
+        .as_ref()
+        .is_some_and(|r| r.chars().count() > MAX_REJECTION_REASON_LENGTH)
+    {
+        return Err(ContractError::RejectionReasonTooLong {
+            max_length: MAX_REJECTION_REASON_LENGTH,
+        });
+    }
+    Ok(())
+}
+
+// Sudo entry point - Only the chain (e.g. governance) can call this
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::UpdateOwner { new_owner } => update_owner(deps, &env, None, new_owner),
+        SudoMsg::UpdateAdmin { new_admin } => update_admin(deps, &env, None, new_admin),
+        SudoMsg::Pause {} => set_paused(deps, &env, None, true),
+        SudoMsg::Unpause {} => set_paused(deps, &env, None, false),
+        SudoMsg::ForcePrice { token_id, price } => sudo_force_price(deps, env, token_id, price),
+        SudoMsg::RemoveUpdater { updater } => remove_whitelisted_updater(deps, &env, None, updater),
+    }
+}
+
+// Force a price, bypassing pause and deviation checks, and supersede any pending updates
+fn sudo_force_price(
+    deps: DepsMut,
+    env: Env,
+    token_id: String,
+    price: Decimal256,
+) -> Result<Response, ContractError> {
+    // Check if token is supported
+    if !is_token_supported(deps.as_ref(), &token_id)? {
+        return Err(ContractError::TokenNotSupported(token_id));
+    }
+    check_price_value(&token_id, price)?;
+
+    let old_price = PRICES.may_load(deps.storage, &token_id)?;
+    write_price(
+        deps.storage,
+        &token_id,
+        price,
+        env.block.time.seconds(),
+        Submitter::Governance {},
+    )?;
+    for id in pending_update_ids(deps.storage, &token_id)? {
+        supersede_pending_update(deps.storage, &env, None, id, None)?;
+    }
+
+    record_audit(
+        deps.storage,
+        &env,
+        None,
+        AuditAction::ForcePrice,
+        Some(&token_id),
+        old_price.map(|p| p.usd.to_string()),
+        Some(price.to_string()),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "force_price")
+        .add_attribute("token_id", token_id)
+        .add_attribute("price", price.to_string()))
+}
+
+// Numeric components of a dotted version, ignoring any pre-release or build suffix
+fn parse_version(version: &str) -> Vec<u64> {
+    version
+        .split(['-', '+'])
+        .next()
+        .unwrap_or_default()
+        .split('.')
+        .map(|part| part.parse().unwrap_or_default())
+        .collect()
+}
+
+// Whitelist entries as stored by any contract version
+#[cw_serde]
+#[serde(untagged)]
+enum StoredUpdaterWeight {
+    Current(Decimal),
+    Legacy(bool), // Before updaters were weighted
+}
+
+const STORED_WHITELISTED_UPDATERS: Map<&Addr, StoredUpdaterWeight> =
+    Map::new("whitelisted_updaters");
+
+// Token entries as stored by any contract version
+#[cw_serde]
+struct StoredTokenInfo {
+    #[serde(default)]
+    supported: Option<bool>, // Only stored before removal deleted tokens
+    deviation_reference: Option<DeviationReference>,
+    deviation_thresholds: Option<DeviationThresholds>,
+    #[serde(default)]
+    decimals: Option<u8>,
+}
+
+const STORED_TOKENS: Map<&str, StoredTokenInfo> = Map::new("tokens");
+
+// Pending updates as stored before they were queued by ID
+const LEGACY_PENDING_UPDATES: Map<&str, PendingUpdate> = Map::new("pending_updates");
+
+// Migrate entry point - Rewrites legacy pending updates, tokens and updaters and bumps the
+// contract version. Prices stored as Decimal need no rewrite: Decimal256 shares its string
+// encoding, and legacy history entries read back with an unknown submitter.
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Only migrate this contract, and never onto an older version. The first release
+    // recorded no contract version.
+    if let Some(stored) = CONTRACT.may_load(deps.storage)? {
+        if stored.contract != CONTRACT_NAME {
+            return Err(ContractError::InvalidMigration(format!(
+                "cannot migrate contract {} onto {}",
+                stored.contract, CONTRACT_NAME
+            )));
+        }
+        if parse_version(&stored.version) > parse_version(CONTRACT_VERSION) {
+            return Err(ContractError::InvalidMigration(format!(
+                "cannot downgrade from version {} to {}",
+                stored.version, CONTRACT_VERSION
+            )));
+        }
+    }
+
+    // Pending updates used to be keyed by token, one per token
+    let mut legacy_pending = LEGACY_PENDING_UPDATES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, update)| update))
+        .collect::<StdResult<Vec<_>>>()?;
+    legacy_pending.sort_by_key(|update| update.requested_at);
+
+    for mut update in legacy_pending.iter().cloned() {
+        LEGACY_PENDING_UPDATES.remove(deps.storage, &update.token_id);
+        update.id = next_pending_update_id(deps.storage)?;
+        save_pending_update(deps.storage, &update)?;
+    }
+
+    // Pending updates used not to be indexed by updater
+    let pending = PENDING_UPDATES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, update)| update))
+        .collect::<StdResult<Vec<_>>>()?;
+    for update in &pending {
+        save_pending_update(deps.storage, update)?;
+    }
+
+    // Removed tokens used to stay stored as unsupported, and every token carried the flag
+    let legacy_tokens = STORED_TOKENS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((token_id, token)) if token.supported.is_some() => Some(Ok((token_id, token))),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut unsupported_tokens = 0;
+    for (token_id, token) in legacy_tokens {
+        if token.supported == Some(false) {
+            TOKENS.remove(deps.storage, &token_id);
+            unsupported_tokens += 1;
+        } else {
+            TOKENS.save(
+                deps.storage,
+                &token_id,
+                &TokenInfo {
+                    deviation_reference: token.deviation_reference,
+                    deviation_thresholds: token.deviation_thresholds,
+                    decimals: token.decimals,
+                },
+            )?;
+        }
+    }
+
+    // Whitelisted updaters used to be stored as flags
+    let legacy_updaters = STORED_WHITELISTED_UPDATERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((addr, StoredUpdaterWeight::Legacy(whitelisted))) => Some(Ok((addr, whitelisted))),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (addr, whitelisted) in &legacy_updaters {
+        if *whitelisted {
+            WHITELISTED_UPDATERS.save(deps.storage, addr, &DEFAULT_UPDATER_WEIGHT)?;
+        } else {
+            WHITELISTED_UPDATERS.remove(deps.storage, addr);
+        }
+    }
+
+    // The total weight used to be summed on every query
+    let total_weight = WHITELISTED_UPDATERS
+        .range(deps.storage, None, None, Order::Ascending)
+        .try_fold(Decimal::zero(), |total, item| -> StdResult<_> {
+            Ok(total.checked_add(item?.1)?)
+        })?;
+    TOTAL_UPDATER_WEIGHT.save(deps.storage, &total_weight)?;
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("migrated_pending_updates", legacy_pending.len().to_string())
+        .add_attribute("migrated_updaters", legacy_updaters.len().to_string())
+        .add_attribute("removed_unsupported_tokens", unsupported_tokens.to_string()))
+}
+
 // Query entry point
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
         QueryMsg::TokenPrice { token_id } => to_json_binary(&query_token_price(deps, token_id)?),
-        QueryMsg::AllPrices {} => to_json_binary(&query_all_prices(deps)?),
+        QueryMsg::TokenConfig { token_id } => to_json_binary(&query_token_config(deps, token_id)?),
+        QueryMsg::TransformedPrice {
+            token_id,
+            invert,
+            per_base_unit,
+        } => to_json_binary(&query_transformed_price(
+            deps,
+            token_id,
+            invert,
+            per_base_unit,
+        )?),
+        QueryMsg::TokenPrices {
+            token_ids,
+            max_age_seconds,
+        } => to_json_binary(&query_token_prices(deps, env, token_ids, max_age_seconds)?),
+        QueryMsg::SimulateUpdate { sender, price_data } => {
+            to_json_binary(&query_simulate_update(deps, env, sender, price_data)?)
+        }
+        QueryMsg::AllPrices { start_after, limit } => {
+            to_json_binary(&query_all_prices(deps, start_after, limit)?)
+        }
         QueryMsg::PriceHistory {
             token_id,
             start_time,
             end_time,
             limit,
         } => to_json_binary(&query_price_history(deps, token_id, start_time, end_time, limit)?),
-        QueryMsg::PendingUpdates {} => to_json_binary(&query_pending_updates(deps)?),
-        QueryMsg::SupportedTokens {} => to_json_binary(&query_supported_tokens(deps)?),
-        QueryMsg::WhitelistedUpdaters {} => to_json_binary(&query_whitelisted_updaters(deps)?),
+        QueryMsg::Twap {
+            token_id,
+            window_seconds,
+        } => to_json_binary(&query_twap(deps, env, token_id, window_seconds)?),
+        QueryMsg::Ema { token_id, period } => to_json_binary(&query_ema(deps, token_id, period)?),
+        QueryMsg::PriceStats {
+            token_id,
+            window_seconds,
+        } => to_json_binary(&query_price_stats(deps, env, token_id, window_seconds)?),
+        QueryMsg::PendingUpdates { start_after, limit } => {
+            to_json_binary(&query_pending_updates(deps, start_after, limit)?)
+        }
+        QueryMsg::TokenPendingUpdates {
+            token_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_token_pending_updates(
+            deps,
+            token_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::SupportedTokens { start_after, limit } => {
+            to_json_binary(&query_supported_tokens(deps, start_after, limit)?)
+        }
+        QueryMsg::WhitelistedUpdaters { start_after, limit } => {
+            to_json_binary(&query_whitelisted_updaters(deps, start_after, limit)?)
+        }
+        QueryMsg::UpdaterWeights { start_after, limit } => {
+            to_json_binary(&query_updater_weights(deps, start_after, limit)?)
+        }
+        QueryMsg::UpdaterStatus { updater } => {
+            to_json_binary(&query_updater_status(deps, env, updater)?)
+        }
+        QueryMsg::Bond { updater } => to_json_binary(&query_bond(deps, updater)?),
+        QueryMsg::RewardPool {} => to_json_binary(&query_reward_pool(deps)?),
+        QueryMsg::PendingRewards { updater } => {
+            to_json_binary(&query_pending_rewards(deps, updater)?)
+        }
+        QueryMsg::UpdaterStatuses { start_after, limit } => {
+            to_json_binary(&query_updater_statuses(deps, env, start_after, limit)?)
+        }
+        QueryMsg::ResolvedUpdates {
+            token_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query_resolved_updates(deps, token_id, start_after, limit)?),
+        QueryMsg::QueuedChanges { start_after, limit } => {
+            to_json_binary(&query_queued_changes(deps, start_after, limit)?)
+        }
+        QueryMsg::AuditLog {
+            action,
+            start_after,
+            limit,
+        } => to_json_binary(&query_audit_log(deps, action, start_after, limit)?),
     }
 }
 
@@ -538,6 +3400,13 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         owner: config.owner.to_string(),
         admin: config.admin.to_string(),
         price_deviation_threshold: config.price_deviation_threshold,
+        paused: config.paused,
+        ema_half_lives: EMA_PERIODS.may_load(deps.storage)?.unwrap_or_default(),
+        guardian: config.guardian.map(|g| g.to_string()),
+        timelock_delay: config.timelock_delay,
+        bonding: BONDING.may_load(deps.storage)?,
+        bootstrap_policy: BOOTSTRAP_POLICY.may_load(deps.storage)?.unwrap_or_default(),
+        aggregation: AGGREGATION.may_load(deps.storage)?,
     })
 }
 
@@ -558,6 +3427,7 @@ fn query_token_price(deps: Deps, token_id: String) -> StdResult<TokenPriceRespon
             token_id,
             price: price.usd,
             last_updated: price.last_updated,
+            submitter: price.submitter,
         }),
         None => Err(StdError::generic_err(format!(
             "No price data for token {}",
@@ -566,17 +3436,149 @@ fn query_token_price(deps: Deps, token_id: String) -> StdResult<TokenPriceRespon
     }
 }
 
+// Query a token price inverted and/or scaled to one base unit
+fn query_transformed_price(
+    deps: Deps,
+    token_id: String,
+    invert: bool,
+    per_base_unit: bool,
+) -> StdResult<TransformedPriceResponse> {
+    let price = query_token_price(deps, token_id)?;
+
+    let decimals = if per_base_unit {
+        let decimals = TOKENS.load(deps.storage, &price.token_id)?.decimals;
+        if decimals.is_none() {
+            return Err(StdError::generic_err(format!(
+                "No decimals set for token {}",
+                price.token_id
+            )));
+        }
+        decimals
+    } else {
+        None
+    };
+
+    let (atomics, exponent) = transform_price(price.price, invert, decimals)?;
+    Ok(TransformedPriceResponse {
+        price: Decimal256::from_atomics(atomics, exponent)
+            .map_err(|e| StdError::generic_err(e.to_string()))?,
+        atomics,
+        exponent,
+        token_id: price.token_id,
+        inverted: invert,
+        decimals,
+        last_updated: price.last_updated,
+    })
+}
+
+// Query per-token configuration
+fn query_token_config(deps: Deps, token_id: String) -> StdResult<TokenConfigResponse> {
+    match TOKENS.may_load(deps.storage, &token_id)? {
+        Some(token) => Ok(TokenConfigResponse {
+            composite: COMPOSITE_FEEDS.may_load(deps.storage, &token_id)?,
+            derived: DERIVED_FEEDS.may_load(deps.storage, &token_id)?,
+            derived_input: DERIVED_INPUTS.may_load(deps.storage, &token_id)?,
+            decimals: token.decimals,
+            token_id,
+            deviation_reference: token
+                .deviation_reference
+                .unwrap_or(DeviationReference::LastPrice {}),
+            deviation_thresholds: token.deviation_thresholds,
+        }),
+        None => Err(StdError::generic_err(format!(
+            "Token {} not supported",
+            token_id
+        ))),
+    }
+}
+
+// Query several token prices at once, reporting a status per token instead of failing
+fn query_token_prices(
+    deps: Deps,
+    env: Env,
+    token_ids: Vec<String>,
+    max_age_seconds: Option<u64>,
+) -> StdResult<TokenPricesResponse> {
+    if token_ids.len() > MAX_PAGE_LIMIT as usize {
+        return Err(StdError::generic_err(format!(
+            "Cannot query more than {} tokens at once",
+            MAX_PAGE_LIMIT
+        )));
+    }
+
+    let current_time = env.block.time.seconds();
+
+    let prices: StdResult<Vec<TokenPriceItem>> = token_ids
+        .into_iter()
+        .map(|token_id| {
+            if !is_token_supported(deps, &token_id)? {
+                return Ok(TokenPriceItem {
+                    token_id,
+                    status: PriceStatus::Unsupported,
+                    price: None,
+                    last_updated: None,
+                });
+            }
+
+            let item = match PRICES.may_load(deps.storage, &token_id)? {
+                Some(price) => {
+                    let is_stale = max_age_seconds.is_some_and(|max_age| {
+                        current_time.saturating_sub(price.last_updated) > max_age
+                    });
+                    TokenPriceItem {
+                        token_id,
+                        status: if is_stale {
+                            PriceStatus::Stale
+                        } else {
+                            PriceStatus::Ok
+                        },
+                        price: Some(price.usd),
+                        last_updated: Some(price.last_updated),
+                    }
+                }
+                None => TokenPriceItem {
+                    token_id,
+                    status: PriceStatus::Missing,
+                    price: None,
+                    last_updated: None,
+                },
+            };
+            Ok(item)
+        })
+        .collect();
+
+    Ok(TokenPricesResponse { prices: prices? })
+}
+
 // Query all prices
-fn query_all_prices(deps: Deps) -> StdResult<AllPricesResponse> {
-    let prices: StdResult<HashMap<String, TokenPrice>> = PRICES
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+fn query_all_prices(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<AllPricesResponse> {
+    let limit = page_limit(limit);
+    let min = start_after.as_deref().map(Bound::exclusive);
+
+    let prices: StdResult<Vec<TokenPriceResponse>> = PRICES
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
         .map(|item| {
-            let (k, v) = item?;
-            Ok((k.to_string(), v))
+            let (token_id, price) = item?;
+            Ok(TokenPriceResponse {
+                token_id,
+                price: price.usd,
+                last_updated: price.last_updated,
+                submitter: price.submitter,
+            })
         })
         .collect();
 
-    Ok(AllPricesResponse { prices: prices? })
+    let (prices, next_start_after) = paginate(prices?, limit, |p| p.token_id.clone());
+
+    Ok(AllPricesResponse {
+        prices,
+        next_start_after,
+    })
 }
 
 // Query price history
@@ -604,11 +3606,15 @@ fn query_price_history(
     let upper_bound = end_time.map(|t| Bound::<u64>::exclusive(t + 1));
 
     let history: StdResult<Vec<PriceHistoryEntry>> = prefix_map
-        .range(deps.storage, lower_bound, upper_bound, cosmwasm_std::Order::Descending)
+        .range(deps.storage, lower_bound, upper_bound, Order::Descending)
         .take(limit)
         .map(|item| {
-            let (timestamp, price) = item?;
-            Ok(PriceHistoryEntry { price, timestamp })
+            let (timestamp, record) = item?;
+            Ok(PriceHistoryEntry {
+                price: record.price,
+                timestamp,
+                submitter: record.submitter,
+            })
         })
         .collect();
 
@@ -618,51 +3624,545 @@ fn query_price_history(
     })
 }
 
+// Query the time-weighted average price over a trailing window
+fn query_twap(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    window_seconds: u64,
+) -> StdResult<TwapResponse> {
+    if !is_token_supported(deps, &token_id)? {
+        return Err(StdError::generic_err(format!(
+            "Token {} not supported",
+            token_id
+        )));
+    }
+
+    match calculate_twap(
+        deps.storage,
+        &token_id,
+        window_seconds,
+        env.block.time.seconds(),
+    )? {
+        Some(twap) => Ok(TwapResponse {
+            token_id,
+            window_seconds,
+            twap,
+        }),
+        None => Err(StdError::generic_err(format!(
+            "No price data for token {}",
+            token_id
+        ))),
+    }
+}
+
+// Query the EMA for a configured half-life
+fn query_ema(deps: Deps, token_id: String, period: u64) -> StdResult<EmaResponse> {
+    if !is_token_supported(deps, &token_id)? {
+        return Err(StdError::generic_err(format!(
+            "Token {} not supported",
+            token_id
+        )));
+    }
+
+    if !EMA_PERIODS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .contains(&period)
+    {
+        return Err(StdError::generic_err(format!(
+            "EMA period {} not configured",
+            period
+        )));
+    }
+
+    match EMAS.may_load(deps.storage, (&token_id, period))? {
+        Some(ema) => Ok(EmaResponse {
+            token_id,
+            period,
+            value: ema.value,
+            last_updated: ema.last_updated,
+        }),
+        None => Err(StdError::generic_err(format!(
+            "No EMA data for token {}",
+            token_id
+        ))),
+    }
+}
+
+// Query summary statistics over the price history within a trailing window
+fn query_price_stats(
+    deps: Deps,
+    env: Env,
+    token_id: String,
+    window_seconds: u64,
+) -> StdResult<PriceStatsResponse> {
+    if !is_token_supported(deps, &token_id)? {
+        return Err(StdError::generic_err(format!(
+            "Token {} not supported",
+            token_id
+        )));
+    }
+
+    let window_start = env.block.time.seconds().saturating_sub(window_seconds);
+
+    // Most recent samples first, capped like history queries, then put back in time order
+    let mut samples: Vec<Decimal256> = PRICE_HISTORY
+        .prefix(token_id.as_str())
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(window_start)),
+            None,
+            Order::Descending,
+        )
+        .take(MAX_HISTORY_LIMIT as usize + 1)
+        .map(|item| item.map(|(_, record)| record.price))
+        .collect::<StdResult<_>>()?;
+    let truncated = samples.len() > MAX_HISTORY_LIMIT as usize;
+    samples.truncate(MAX_HISTORY_LIMIT as usize);
+    samples.reverse();
+
+    let mut stats = PriceStatsResponse {
+        token_id,
+        window_seconds,
+        sample_count: samples.len() as u32,
+        truncated,
+        min: None,
+        max: None,
+        mean: None,
+        last: samples.last().copied(),
+        volatility: None,
+        max_drawdown: None,
+    };
+
+    if samples.is_empty() {
+        return Ok(stats);
+    }
+
+    let mut sum = Decimal256::zero();
+    let mut peak = Decimal256::zero();
+    let mut max_drawdown = Decimal256::zero();
+    let mut returns = Vec::with_capacity(samples.len());
+
+    for (i, price) in samples.iter().enumerate() {
+        stats.min = Some(stats.min.map_or(*price, |min| min.min(*price)));
+        stats.max = Some(stats.max.map_or(*price, |max| max.max(*price)));
+        sum += *price;
+
+        if *price > peak {
+            peak = *price;
+        } else if !peak.is_zero() {
+            max_drawdown = max_drawdown.max((peak - *price) / peak);
+        }
+
+        // Returns involving a zero price are undefined and skipped
+        if i > 0 && !samples[i - 1].is_zero() && !price.is_zero() {
+            returns.push(log_return(samples[i - 1], *price)?);
+        }
+    }
+
+    stats.mean = Some(sum / Decimal256::from_ratio(samples.len() as u128, 1u128));
+    stats.volatility = sample_std_dev(&returns)?;
+    stats.max_drawdown =
+        Some(Decimal::try_from(max_drawdown).map_err(|e| StdError::generic_err(e.to_string()))?);
+
+    Ok(stats)
+}
+
 // Query pending updates
-fn query_pending_updates(deps: Deps) -> StdResult<PendingUpdatesResponse> {
+fn query_pending_updates(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PendingUpdatesResponse> {
+    let limit = page_limit(limit);
+    let min = start_after.map(Bound::exclusive);
+
     let updates: StdResult<Vec<PendingUpdate>> = PENDING_UPDATES
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
         .map(|item| {
             let (_, update) = item?;
             Ok(update)
         })
         .collect();
 
-    Ok(PendingUpdatesResponse { updates: updates? })
+    let (updates, next_start_after) = paginate(updates?, limit, |u| u.id);
+
+    Ok(PendingUpdatesResponse {
+        updates,
+        next_start_after,
+    })
+}
+
+// Query pending updates queued for one token
+fn query_token_pending_updates(
+    deps: Deps,
+    token_id: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<PendingUpdatesResponse> {
+    let limit = page_limit(limit);
+    let min = start_after.map(Bound::exclusive);
+
+    let updates: StdResult<Vec<PendingUpdate>> = PENDING_UPDATES_BY_TOKEN
+        .prefix(token_id.as_str())
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|id| PENDING_UPDATES.load(deps.storage, id?))
+        .collect();
+
+    let (updates, next_start_after) = paginate(updates?, limit, |u| u.id);
+
+    Ok(PendingUpdatesResponse {
+        updates,
+        next_start_after,
+    })
 }
 
 // Query supported tokens
-fn query_supported_tokens(deps: Deps) -> StdResult<SupportedTokensResponse> {
-    let tokens: StdResult<Vec<String>> = TOKENS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-        .filter_map(|item| {
-            let (token_id, token_info) = match item {
-                Ok((k, v)) => (k, v),
-                Err(e) => return Some(Err(e)),
-            };
+fn query_supported_tokens(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<SupportedTokensResponse> {
+    let limit = page_limit(limit);
+    let min = start_after.as_deref().map(Bound::exclusive);
 
-            if token_info.supported {
-                Some(Ok(token_id.to_string()))
-            } else {
-                None
-            }
-        })
+    let tokens: StdResult<Vec<String>> = TOKENS
+        .keys(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
         .collect();
 
-    Ok(SupportedTokensResponse { tokens: tokens? })
+    let (tokens, next_start_after) = paginate(tokens?, limit, |t| t.clone());
+
+    Ok(SupportedTokensResponse {
+        tokens,
+        next_start_after,
+    })
 }
 
 // Query whitelisted updaters
-fn query_whitelisted_updaters(deps: Deps) -> StdResult<WhitelistedUpdatersResponse> {
+fn query_whitelisted_updaters(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<WhitelistedUpdatersResponse> {
+    let limit = page_limit(limit);
+    let start_addr = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let min = start_addr.as_ref().map(Bound::exclusive);
+
     let updaters: StdResult<Vec<String>> = WHITELISTED_UPDATERS
-        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
         .map(|item| {
             let (addr, _) = item?;
             Ok(addr.to_string())
         })
         .collect();
 
+    let (updaters, next_start_after) = paginate(updaters?, limit, |u| u.clone());
+
     Ok(WhitelistedUpdatersResponse {
-        updaters: updaters?,
+        updaters,
+        next_start_after,
+    })
+}
+
+// Query whitelisted updaters' weights and their share of the total weight
+fn query_updater_weights(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<UpdaterWeightsResponse> {
+    let limit = page_limit(limit);
+    let start_addr = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let min = start_addr.as_ref().map(Bound::exclusive);
+
+    let total_weight = TOTAL_UPDATER_WEIGHT
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+
+    let weights: StdResult<Vec<UpdaterWeight>> = WHITELISTED_UPDATERS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            let (addr, weight) = item?;
+            Ok(UpdaterWeight {
+                updater: addr.to_string(),
+                weight,
+                share: weight.checked_div(total_weight).unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    let (weights, next_start_after) = paginate(weights?, limit, |w| w.updater.clone());
+
+    Ok(UpdaterWeightsResponse {
+        weights,
+        total_weight,
+        next_start_after,
+    })
+}
+
+// Query submission stats for one updater
+fn query_updater_status(deps: Deps, env: Env, updater: String) -> StdResult<UpdaterStatusResponse> {
+    let updater = deps.api.addr_validate(&updater)?;
+    let weight = WHITELISTED_UPDATERS.may_load(deps.storage, &updater)?;
+    updater_status(deps, &env, updater, weight)
+}
+
+// Query an updater's bond and whether it may submit prices
+fn query_bond(deps: Deps, updater: String) -> StdResult<BondResponse> {
+    let updater = deps.api.addr_validate(&updater)?;
+    let bond = BONDS.may_load(deps.storage, &updater)?.unwrap_or_default();
+    let whitelisted = is_whitelisted(deps, &updater)?;
+    let bonded_enough = match BONDING.may_load(deps.storage)? {
+        Some(bonding) => is_admin(deps, &updater)? || bond.bonded >= bonding.min_bond,
+        None => true,
+    };
+
+    Ok(BondResponse {
+        updater: updater.to_string(),
+        bonded: bond.bonded,
+        unbonding: bond.unbonding,
+        release_at: (!bond.unbonding.is_zero()).then_some(bond.release_at),
+        active: whitelisted && bonded_enough,
+    })
+}
+
+// Query the reward rate and pool balances
+fn query_reward_pool(deps: Deps) -> StdResult<RewardPoolResponse> {
+    let pool = REWARD_POOL.may_load(deps.storage)?.unwrap_or_default();
+    Ok(RewardPoolResponse {
+        config: REWARDS.may_load(deps.storage)?,
+        available: pool.available,
+        accrued: pool.accrued,
+    })
+}
+
+// Query rewards an updater can claim
+fn query_pending_rewards(deps: Deps, updater: String) -> StdResult<PendingRewardsResponse> {
+    let updater = deps.api.addr_validate(&updater)?;
+    Ok(PendingRewardsResponse {
+        amount: ACCRUED_REWARDS
+            .may_load(deps.storage, &updater)?
+            .unwrap_or_default(),
+        updater: updater.to_string(),
+    })
+}
+
+// Query submission stats for every whitelisted updater
+fn query_updater_statuses(
+    deps: Deps,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<UpdaterStatusesResponse> {
+    let limit = page_limit(limit);
+    let start_addr = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let min = start_addr.as_ref().map(Bound::exclusive);
+
+    let updaters: StdResult<Vec<UpdaterStatusResponse>> = WHITELISTED_UPDATERS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| {
+            let (addr, weight) = item?;
+            updater_status(deps, &env, addr, Some(weight))
+        })
+        .collect();
+
+    let (updaters, next_start_after) = paginate(updaters?, limit, |u| u.updater.clone());
+
+    Ok(UpdaterStatusesResponse {
+        updaters,
+        next_start_after,
+    })
+}
+
+fn updater_status(
+    deps: Deps,
+    env: &Env,
+    updater: Addr,
+    weight: Option<Decimal>, // None when not whitelisted
+) -> StdResult<UpdaterStatusResponse> {
+    let stats = UPDATER_STATS.may_load(deps.storage, &updater)?;
+    let last_submission = stats.as_ref().map(|s| s.last_submission);
+    let stats = stats.unwrap_or_default();
+
+    Ok(UpdaterStatusResponse {
+        updater: updater.to_string(),
+        whitelisted: weight.is_some(),
+        last_submission,
+        seconds_since_last_submission: last_submission
+            .map(|t| env.block.time.seconds().saturating_sub(t)),
+        submission_count: stats.submission_count,
+        pending_count: stats.pending_count,
+        outlier_count: stats.outlier_count,
+        tokens_covered: stats.tokens_covered,
+        weight: weight.unwrap_or_default(),
+    })
+}
+
+// Query approved and rejected updates for a token
+fn query_resolved_updates(
+    deps: Deps,
+    token_id: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ResolvedUpdatesResponse> {
+    let limit = page_limit(limit);
+    let min = start_after.map(Bound::exclusive);
+
+    let updates: StdResult<Vec<ResolvedUpdate>> = RESOLVED_UPDATES
+        .prefix(token_id.as_str())
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| item.map(|(_, update)| update))
+        .collect();
+
+    let (updates, next_start_after) = paginate(updates?, limit, |u| u.id);
+
+    Ok(ResolvedUpdatesResponse {
+        updates,
+        next_start_after,
+    })
+}
+
+// Run the UpdatePrices checks for a sender without writing anything
+fn query_simulate_update(
+    deps: Deps,
+    env: Env,
+    sender: String,
+    price_data: PriceData,
+) -> StdResult<SimulateUpdateResponse> {
+    let sender = deps.api.addr_validate(&sender)?;
+    let config = CONFIG.load(deps.storage)?;
+    let current_time = env.block.time.seconds();
+    let sender_error = check_updater(deps, &config, &sender)
+        .err()
+        .map(|err| err.to_string());
+
+    let mut prices: Vec<_> = price_data.prices.into_iter().collect();
+    prices.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let results: Vec<SimulatedPrice> = prices
+        .into_iter()
+        .map(|(token_id, price_info)| {
+            let check = match &sender_error {
+                Some(error) => Err(error.clone()),
+                None => check_price_submission(
+                    deps,
+                    &config,
+                    &sender,
+                    &token_id,
+                    price_info.usd,
+                    current_time,
+                )
+                .map_err(|err| err.to_string()),
+            };
+            let (outcome, deviation) = match check {
+                Ok(PriceCheck::Accept { deviation }) => (SimulatedOutcome::Updated, deviation),
+                Ok(PriceCheck::Pending { deviation, .. }) => {
+                    (SimulatedOutcome::Pending, Some(deviation))
+                }
+                Ok(PriceCheck::Collect {}) => (SimulatedOutcome::Collecting {}, None),
+                Err(error) => (SimulatedOutcome::Failed { error }, None),
+            };
+            SimulatedPrice {
+                token_id,
+                outcome,
+                deviation,
+            }
+        })
+        .collect();
+
+    Ok(SimulateUpdateResponse {
+        applies: sender_error.is_none()
+            && results
+                .iter()
+                .all(|r| !matches!(r.outcome, SimulatedOutcome::Failed { .. })),
+        results,
+    })
+}
+
+// Query changes waiting out the timelock
+fn query_queued_changes(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueuedChangesResponse> {
+    let limit = page_limit(limit);
+    let min = start_after.map(Bound::exclusive);
+
+    let changes: StdResult<Vec<QueuedChange>> = QUEUED_CHANGES
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit + 1)
+        .map(|item| item.map(|(_, change)| change))
+        .collect();
+
+    let (changes, next_start_after) = paginate(changes?, limit, |c| c.id);
+
+    Ok(QueuedChangesResponse {
+        changes,
+        next_start_after,
+    })
+}
+
+// Query the audit log, optionally restricted to one kind of action
+fn query_audit_log(
+    deps: Deps,
+    action: Option<AuditAction>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<AuditLogResponse> {
+    let limit = page_limit(limit);
+    let min = start_after.map(Bound::exclusive);
+
+    let entries: StdResult<Vec<AuditEntry>> = match action {
+        Some(action) => AUDIT_LOG_BY_ACTION
+            .prefix(action.as_str())
+            .keys(deps.storage, min, None, Order::Ascending)
+            .take(limit + 1)
+            .map(|id| AUDIT_LOG.load(deps.storage, id?))
+            .collect(),
+        None => AUDIT_LOG
+            .range(deps.storage, min, None, Order::Ascending)
+            .take(limit + 1)
+            .map(|item| item.map(|(_, entry)| entry))
+            .collect(),
+    };
+
+    let (entries, next_start_after) = paginate(entries?, limit, |e| e.id);
+
+    Ok(AuditLogResponse {
+        entries,
+        next_start_after,
     })
 }
+
+// Clamp a requested page size to the allowed range
+fn page_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize
+}
+
+// Trim a page fetched with one extra item and derive the next cursor from it
+fn paginate<T, K>(
+    mut items: Vec<T>,
+    limit: usize,
+    cursor: impl Fn(&T) -> K,
+) -> (Vec<T>, Option<K>) {
+    if items.len() > limit {
+        items.truncate(limit);
+        let next = items.last().map(cursor);
+        (items, next)
+    } else {
+        (items, None)
+    }
+}
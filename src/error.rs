@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -9,6 +9,9 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Price updates are paused")]
+    Paused {},
+
     #[error("Token {0} not supported")]
     TokenNotSupported(String),
 
@@ -27,6 +30,105 @@ pub enum ContractError {
     #[error("No pending update for token {0}")]
     NoPendingUpdate(String),
 
+    #[error("Pending update {0} not found")]
+    PendingUpdateNotFound(u64),
+
+    #[error("Queued change {0} not found")]
+    QueuedChangeNotFound(u64),
+
+    #[error("Queued change {id} cannot be executed before {execute_after}")]
+    TimelockNotExpired { id: u64, execute_after: u64 },
+
+    #[error("Timelock delay cannot exceed {max_seconds} seconds")]
+    TimelockDelayTooLong { max_seconds: u64 },
+
+    #[error("Rejection reason exceeds {max_length} characters")]
+    RejectionReasonTooLong { max_length: usize },
+
+    #[error("Bonding is not enabled")]
+    BondingDisabled {},
+
+    #[error("Invalid bonding config: {0}")]
+    InvalidBondingConfig(String),
+
+    #[error("Bond denom cannot change while funds are bonded")]
+    BondDenomLocked {},
+
+    #[error("Bonding cannot be disabled while funds are bonded")]
+    BondsOutstanding {},
+
+    #[error("Invalid funds: {0}")]
+    InvalidFunds(String),
+
+    #[error("Bond of {bonded} is below the required {required}")]
+    InsufficientBond { required: Uint128, bonded: Uint128 },
+
+    #[error("Cannot unbond more than the bonded {bonded}")]
+    UnbondExceedsBond { bonded: Uint128 },
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
+
+    #[error("Unbonding funds cannot be claimed before {release_at}")]
+    UnbondingNotComplete { release_at: u64 },
+
+    #[error("Unbonded funds cannot be claimed while submissions await review")]
+    PendingUpdatesUnresolved {},
+
+    #[error("Invalid reward config: {0}")]
+    InvalidRewardConfig(String),
+
+    #[error("Rewards are not enabled")]
+    RewardsDisabled {},
+
+    #[error("Reward denom cannot change while rewards are held")]
+    RewardDenomLocked {},
+
+    #[error("Invalid composite feed: {0}")]
+    InvalidCompositeFeed(String),
+
+    #[error("Feed {0} would depend on itself")]
+    FeedCycle(String),
+
+    #[error("Invalid derived feed: {0}")]
+    InvalidDerivedFeed(String),
+
+    #[error("Invalid derived input: {0}")]
+    InvalidDerivedInput(String),
+
+    #[error("Token {0} is derived from other feeds and cannot be submitted")]
+    DerivedFeed(String),
+
+    #[error("Token {token_id} is a component of {dependent}")]
+    TokenInUse { token_id: String, dependent: String },
+
+    #[error("Price for token {0} must be greater than zero")]
+    ZeroPrice(String),
+
+    #[error("Invalid bootstrap policy: {0}")]
+    InvalidBootstrapPolicy(String),
+
+    #[error("Updater weight must be greater than zero")]
+    InvalidUpdaterWeight {},
+
+    #[error("Invalid aggregation config: {0}")]
+    InvalidAggregationConfig(String),
+
+    #[error("{surviving} submissions survived outlier filtering, {required} required")]
+    InsufficientSubmissions { required: u32, surviving: u32 },
+
+    #[error("Token decimals cannot exceed {max}")]
+    DecimalsTooLarge { max: u8 },
+
+    #[error("Invalid migration: {0}")]
+    InvalidMigration(String),
+
     #[error("Invalid threshold: {0}")]
     InvalidThreshold(String),
+
+    #[error("Invalid EMA periods: {0}")]
+    InvalidEmaPeriods(String),
+
+    #[error("Invalid deviation reference: {0}")]
+    InvalidDeviationReference(String),
 }
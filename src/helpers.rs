@@ -1,17 +1,31 @@
-use cosmwasm_std::{Addr, Decimal, Deps, StdResult};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, ConversionOverflowError, CosmosMsg, CustomQuery, Decimal, Decimal256,
+    Deps, Empty, Order, QuerierWrapper, SignedDecimal, StdError, StdResult, Storage, Timestamp,
+    Uint128, Uint256, WasmMsg,
+};
+use cw_storage_plus::Bound;
+use std::collections::HashMap;
 use std::ops::Div;
 
 use crate::error::ContractError;
-use crate::state::{CONFIG, TOKENS, WHITELISTED_UPDATERS};
+use crate::msg::{
+    CompositeFeed, DeviationThresholds, ExecuteMsg, PriceData, QueryMsg, TokenPriceInfo,
+    TokenPriceItem, TokenPriceResponse, TokenPricesResponse, TwapResponse,
+};
+use crate::state::{CONFIG, PRICE_HISTORY, TOKENS, WHITELISTED_UPDATERS};
+
+// Maximum history entries walked when computing a TWAP
+const MAX_TWAP_SAMPLES: usize = 1000;
 
 pub fn is_owner(deps: Deps, address: &Addr) -> StdResult<bool> {
     let config = CONFIG.load(deps.storage)?;
-    Ok(address == &config.owner)
+    Ok(*address == config.owner)
 }
 
 pub fn is_admin(deps: Deps, address: &Addr) -> StdResult<bool> {
     let config = CONFIG.load(deps.storage)?;
-    Ok(address == &config.admin || address == &config.owner)
+    Ok(*address == config.admin || *address == config.owner)
 }
 
 pub fn is_whitelisted(deps: Deps, address: &Addr) -> StdResult<bool> {
@@ -19,19 +33,20 @@ pub fn is_whitelisted(deps: Deps, address: &Addr) -> StdResult<bool> {
     if is_admin(deps, address)? {
         return Ok(true);
     }
-    Ok(WHITELISTED_UPDATERS.may_load(deps.storage, address)?.unwrap_or(false))
+    Ok(WHITELISTED_UPDATERS.has(deps.storage, address))
 }
 
 pub fn is_token_supported(deps: Deps, token_id: &str) -> StdResult<bool> {
-    match TOKENS.may_load(deps.storage, token_id)? {
-        Some(token_info) => Ok(token_info.supported),
-        None => Ok(false),
-    }
+    // Removed tokens are deleted, so every stored token is supported
+    Ok(TOKENS.has(deps.storage, token_id))
 }
 
-pub fn calculate_price_deviation(old_price: Decimal, new_price: Decimal) -> StdResult<Decimal> {
+pub fn calculate_price_deviation(
+    old_price: Decimal256,
+    new_price: Decimal256,
+) -> StdResult<Decimal256> {
     if old_price.is_zero() {
-        return Ok(Decimal::percent(100));
+        return Ok(Decimal256::percent(100));
     }
 
     let difference = if new_price > old_price {
@@ -40,7 +55,7 @@ pub fn calculate_price_deviation(old_price: Decimal, new_price: Decimal) -> StdR
         old_price - new_price
     };
 
-    Ok(difference.div(old_price) * Decimal::percent(100))
+    Ok(difference.div(old_price) * Decimal256::percent(100))
 }
 
 pub fn validate_threshold(threshold: Decimal) -> Result<(), ContractError> {
@@ -51,3 +66,439 @@ pub fn validate_threshold(threshold: Decimal) -> Result<(), ContractError> {
     }
     Ok(())
 }
+
+pub fn validate_deviation_thresholds(
+    thresholds: &DeviationThresholds,
+) -> Result<(), ContractError> {
+    validate_threshold(thresholds.up)?;
+    validate_threshold(thresholds.down)?;
+
+    if let Some(scaling) = &thresholds.time_scaling {
+        if scaling.reference_seconds == 0 {
+            return Err(ContractError::InvalidThreshold(
+                "Time scaling reference must be greater than zero seconds".to_string(),
+            ));
+        }
+        if scaling.min_multiplier.is_zero() || scaling.min_multiplier > scaling.max_multiplier {
+            return Err(ContractError::InvalidThreshold(
+                "Time scaling multipliers must satisfy 0 < min <= max".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_composite_feed(feed: &CompositeFeed) -> Result<(), ContractError> {
+    if feed.components.is_empty() {
+        return Err(ContractError::InvalidCompositeFeed(
+            "Components cannot be empty".to_string(),
+        ));
+    }
+    if feed.components.iter().any(|c| c.weight.is_zero()) {
+        return Err(ContractError::InvalidCompositeFeed(
+            "Component weights must be greater than zero".to_string(),
+        ));
+    }
+    if feed.divisor.is_some_and(|d| d.is_zero()) {
+        return Err(ContractError::InvalidCompositeFeed(
+            "Divisor must be greater than zero".to_string(),
+        ));
+    }
+
+    let mut token_ids: Vec<&str> = feed
+        .components
+        .iter()
+        .map(|c| c.token_id.as_str())
+        .collect();
+    token_ids.sort_unstable();
+    if token_ids.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(ContractError::InvalidCompositeFeed(
+            "Components must be distinct".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// Apply query-time transformations to a USD price: inversion to tokens per USD and/or
+// scaling to one base unit of a token with the given decimals. Returns the result as
+// atomics over 10^exponent, so a base unit price below 10^-18 USD keeps its digits.
+pub fn transform_price(
+    price: Decimal256,
+    invert: bool,
+    decimals: Option<u8>,
+) -> StdResult<(Uint256, u32)> {
+    let decimals = u32::from(decimals.unwrap_or_default());
+
+    if !invert {
+        // Scaling down only moves the decimal point
+        return Ok((price.atomics(), Decimal256::DECIMAL_PLACES + decimals));
+    }
+
+    if price.is_zero() {
+        return Err(StdError::generic_err("Cannot invert a zero price"));
+    }
+    let unit = Decimal256::from_ratio(Uint256::from(10u128).pow(decimals), 1u128);
+    let inverted = unit
+        .checked_div(price)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    Ok((inverted.atomics(), Decimal256::DECIMAL_PLACES))
+}
+
+// Fair price of one LP share of a constant-product pool: 2 * sqrt(k * price_a * price_b) / supply.
+// Only the invariant k = reserve_a * reserve_b enters the price, so trading the pool
+// off balance cannot move it. Reserves and supply are in whole tokens, like the prices.
+pub fn fair_lp_price(
+    reserve_a: Decimal256,
+    reserve_b: Decimal256,
+    total_supply: Decimal256,
+    price_a: Decimal256,
+    price_b: Decimal256,
+) -> StdResult<Decimal256> {
+    let sqrt_k = reserve_a.checked_mul(reserve_b)?.sqrt();
+    let sqrt_prices = price_a.checked_mul(price_b)?.sqrt();
+
+    let value = sqrt_k
+        .checked_mul(sqrt_prices)?
+        .checked_mul(Decimal256::from_ratio(2u128, 1u128))?;
+    value
+        .checked_div(total_supply)
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+// Amount of base units in whole tokens of a token with the given decimals; without
+// decimals the amount is already in whole tokens
+pub fn whole_tokens(amount: Uint128, decimals: Option<u8>) -> StdResult<Decimal256> {
+    let unit = Uint256::from(10u128).pow(decimals.unwrap_or_default().into());
+    Decimal256::checked_from_ratio(amount, unit).map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+// Threshold that applies to a move from old_price to new_price, `elapsed_seconds` after
+// the last update. Rises use the up threshold and drops the down threshold; with time
+// scaling the threshold grows with the square root of the time since the last update.
+pub fn deviation_threshold(
+    thresholds: &DeviationThresholds,
+    old_price: Decimal256,
+    new_price: Decimal256,
+    elapsed_seconds: u64,
+) -> Decimal {
+    let base = if new_price >= old_price {
+        thresholds.up
+    } else {
+        thresholds.down
+    };
+
+    match &thresholds.time_scaling {
+        Some(scaling) => {
+            let multiplier = Decimal::from_ratio(elapsed_seconds, scaling.reference_seconds)
+                .sqrt()
+                .clamp(scaling.min_multiplier, scaling.max_multiplier);
+            base * multiplier
+        }
+        None => base,
+    }
+}
+
+// ln(2) with 18 fractional digits
+const LN_2: Decimal = Decimal::raw(693_147_180_559_945_309);
+const SIGNED_LN_2: SignedDecimal = SignedDecimal::raw(693_147_180_559_945_309);
+
+// Natural logarithm of new_price / old_price.
+// The ratio is first reduced into [0.5, 2] by powers of two, then ln is
+// approximated with the atanh series 2 * (y + y^3/3 + y^5/5 + ...), y = (x-1)/(x+1).
+pub fn log_return(old_price: Decimal256, new_price: Decimal256) -> StdResult<SignedDecimal> {
+    if old_price.is_zero() || new_price.is_zero() {
+        return Err(StdError::generic_err(
+            "Log return is undefined for a zero price",
+        ));
+    }
+
+    let mut ratio = new_price
+        .checked_div(old_price)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    if ratio.is_zero() {
+        return Err(StdError::generic_err(
+            "Price ratio is too small to represent",
+        ));
+    }
+
+    let two = Decimal256::from_ratio(2u128, 1u128);
+    let half = Decimal256::percent(50);
+    let mut exponent: i64 = 0;
+    while ratio > two {
+        ratio /= two;
+        exponent += 1;
+    }
+    while ratio < half {
+        ratio *= two;
+        exponent -= 1;
+    }
+
+    let ratio = Decimal::try_from(ratio).map_err(|e| StdError::generic_err(e.to_string()))?;
+    let x = SignedDecimal::try_from(ratio).map_err(|e| StdError::generic_err(e.to_string()))?;
+    let y = (x - SignedDecimal::one()) / (x + SignedDecimal::one());
+    let y_squared = y * y;
+
+    let mut sum = SignedDecimal::zero();
+    let mut power = y;
+    let mut n: i64 = 1;
+    loop {
+        let term = power / SignedDecimal::from_ratio(n, 1);
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        power *= y_squared;
+        n += 2;
+    }
+
+    Ok(
+        sum * SignedDecimal::from_ratio(2, 1)
+            + SIGNED_LN_2 * SignedDecimal::from_ratio(exponent, 1),
+    )
+}
+
+// Sample standard deviation; None when fewer than two values are given
+pub fn sample_std_dev(values: &[SignedDecimal]) -> StdResult<Option<Decimal>> {
+    if values.len() < 2 {
+        return Ok(None);
+    }
+
+    let count = SignedDecimal::from_ratio(values.len() as i64, 1);
+    let mean = values.iter().fold(SignedDecimal::zero(), |acc, v| acc + *v) / count;
+
+    let squared_deviations = values.iter().fold(SignedDecimal::zero(), |acc, v| {
+        let diff = *v - mean;
+        acc + diff * diff
+    });
+    let variance = squared_deviations / (count - SignedDecimal::one());
+
+    let variance = Decimal::try_from(variance).map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(Some(variance.sqrt()))
+}
+
+// Fraction of an EMA that survives after `elapsed` seconds: 2^(-elapsed / half_life).
+// Whole half-lives are applied as exact halvings; the remainder uses e^(-f * ln 2).
+pub fn half_life_decay(elapsed: u64, half_life: u64) -> Decimal {
+    let whole = elapsed / half_life;
+    // 2^-60 is already below the 18 decimal places Decimal can hold
+    if whole >= 60 {
+        return Decimal::zero();
+    }
+
+    let whole_decay = Decimal::percent(50).pow(whole as u32);
+    let fraction = Decimal::from_ratio(elapsed % half_life, half_life);
+    let x = fraction * LN_2;
+
+    // e^x by Taylor series, x is in [0, ln 2)
+    let mut exp = Decimal::one();
+    let mut term = Decimal::one();
+    let mut n: u128 = 1;
+    loop {
+        term = term * x / Decimal::from_ratio(n, 1u128);
+        if term.is_zero() {
+            break;
+        }
+        exp += term;
+        n += 1;
+    }
+
+    whole_decay / exp
+}
+
+// Time-weighted average price over [current_time - window, current_time].
+// Each history entry is weighted by how long it stayed the latest price inside the window,
+// including the entry that was already in effect when the window opened.
+pub fn calculate_twap(
+    storage: &dyn Storage,
+    token_id: &str,
+    window_seconds: u64,
+    current_time: u64,
+) -> StdResult<Option<Decimal256>> {
+    let window_start = current_time.saturating_sub(window_seconds);
+
+    let mut weighted_sum = Decimal256::zero();
+    let mut total_weight: u64 = 0;
+    let mut segment_end = current_time;
+    let mut latest = None;
+
+    for item in PRICE_HISTORY
+        .prefix(token_id)
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(current_time)),
+            Order::Descending,
+        )
+        .take(MAX_TWAP_SAMPLES)
+    {
+        let (timestamp, record) = item?;
+        let price = record.price;
+        latest.get_or_insert(price);
+
+        let segment_start = timestamp.max(window_start);
+        let weight = segment_end - segment_start;
+        weighted_sum += price * Decimal256::from_ratio(weight, 1u64);
+        total_weight += weight;
+        segment_end = segment_start;
+
+        if timestamp <= window_start {
+            break;
+        }
+    }
+
+    if total_weight == 0 {
+        // Only a price written at this exact second, or no history at all
+        return Ok(latest);
+    }
+
+    Ok(Some(
+        weighted_sum / Decimal256::from_ratio(total_weight, 1u64),
+    ))
+}
+
+/// OracleContract is a wrapper around Addr that provides helpers for
+/// consumer contracts working with this oracle
+#[cw_serde]
+pub struct OracleContract(pub Addr);
+
+impl OracleContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call<T: Into<ExecuteMsg>>(&self, msg: T) -> StdResult<CosmosMsg> {
+        let msg = to_json_binary(&msg.into())?;
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr().into(),
+            msg,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    pub fn update_prices(
+        &self,
+        prices: HashMap<String, impl Into<Decimal256>>,
+    ) -> StdResult<CosmosMsg> {
+        let prices = prices
+            .into_iter()
+            .map(|(token_id, usd)| (token_id, TokenPriceInfo { usd: usd.into() }))
+            .collect();
+        self.call(ExecuteMsg::UpdatePrices {
+            price_data: PriceData { prices },
+        })
+    }
+
+    pub fn update_single_price(
+        &self,
+        token_id: impl Into<String>,
+        usd: impl Into<Decimal256>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateSinglePrice {
+            token_id: token_id.into(),
+            price_info: TokenPriceInfo { usd: usd.into() },
+        })
+    }
+
+    pub fn querier<'a, C: CustomQuery>(
+        &self,
+        querier: &'a QuerierWrapper<'a, C>,
+    ) -> OracleQuerier<'a, C> {
+        OracleQuerier::new(querier, self.addr())
+    }
+}
+
+/// Typed queries against the oracle, optionally rejecting prices older than a max age
+pub struct OracleQuerier<'a, C: CustomQuery = Empty> {
+    querier: &'a QuerierWrapper<'a, C>,
+    addr: Addr,
+    staleness: Option<(Timestamp, u64)>,
+}
+
+impl<'a, C: CustomQuery> OracleQuerier<'a, C> {
+    pub fn new(querier: &'a QuerierWrapper<'a, C>, addr: Addr) -> Self {
+        OracleQuerier {
+            querier,
+            addr,
+            staleness: None,
+        }
+    }
+
+    /// Reject prices last updated more than `max_age_seconds` before `block_time`
+    pub fn with_max_age(mut self, block_time: Timestamp, max_age_seconds: u64) -> Self {
+        self.staleness = Some((block_time, max_age_seconds));
+        self
+    }
+
+    pub fn price(&self, token_id: impl Into<String>) -> StdResult<TokenPriceResponse> {
+        let res: TokenPriceResponse = self.querier.query_wasm_smart(
+            self.addr.clone(),
+            &QueryMsg::TokenPrice {
+                token_id: token_id.into(),
+            },
+        )?;
+
+        if let Some((block_time, max_age)) = self.staleness {
+            if block_time.seconds().saturating_sub(res.last_updated) > max_age {
+                return Err(StdError::generic_err(format!(
+                    "Price for token {} is stale",
+                    res.token_id
+                )));
+            }
+        }
+        Ok(res)
+    }
+
+    /// Prices for several tokens with a status per token; staleness is reported, not enforced
+    pub fn prices(&self, token_ids: Vec<String>) -> StdResult<Vec<TokenPriceItem>> {
+        let res: TokenPricesResponse = self.querier.query_wasm_smart(
+            self.addr.clone(),
+            &QueryMsg::TokenPrices {
+                token_ids,
+                max_age_seconds: self.staleness.map(|(_, max_age)| max_age),
+            },
+        )?;
+        Ok(res.prices)
+    }
+
+    /// Time-weighted average price; with a max age set, the latest price must also be fresh
+    pub fn twap(&self, token_id: impl Into<String>, window_seconds: u64) -> StdResult<Decimal256> {
+        let token_id = token_id.into();
+        if self.staleness.is_some() {
+            self.price(token_id.clone())?;
+        }
+
+        let res: TwapResponse = self.querier.query_wasm_smart(
+            self.addr.clone(),
+            &QueryMsg::Twap {
+                token_id,
+                window_seconds,
+            },
+        )?;
+        Ok(res.twap)
+    }
+
+    /// Convert an amount of `from` into `to` using both USD prices, rounding down
+    pub fn convert(
+        &self,
+        amount: Uint128,
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> StdResult<Uint128> {
+        let from_price = self.price(from)?.price;
+        let to = self.price(to)?;
+        if to.price.is_zero() {
+            return Err(StdError::generic_err(format!(
+                "Price for token {} is zero",
+                to.token_id
+            )));
+        }
+
+        Uint256::from(amount)
+            .checked_multiply_ratio(from_price.atomics(), to.price.atomics())
+            .map_err(|e| StdError::generic_err(e.to_string()))?
+            .try_into()
+            .map_err(|e: ConversionOverflowError| StdError::generic_err(e.to_string()))
+    }
+}
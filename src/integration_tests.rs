@@ -3,16 +3,20 @@ use crate::{
     PendingUpdatesResponse, PriceHistoryResponse, QueryMsg,
     TokenPriceResponse, WhitelistedUpdatersResponse,
 };
-use cosmwasm_std::{Decimal, Empty};
+use cosmwasm_std::{Decimal, Decimal256, Empty};
 use cw_multi_test::{App, Contract, ContractWrapper, Executor, IntoAddr};
 use std::collections::HashMap;
 
 fn gg_oracle_price_contract() -> Box<dyn Contract<Empty>> {
-    Box::new(ContractWrapper::new_with_empty(
-        contract::execute,
-        contract::instantiate,
-        contract::query,
-    ))
+    Box::new(
+        ContractWrapper::new_with_empty(
+            contract::execute,
+            contract::instantiate,
+            contract::query,
+        )
+        .with_sudo_empty(contract::sudo)
+            .with_migrate_empty(contract::migrate),
+    )
 }
 
 #[test]
@@ -452,7 +456,7 @@ mod whitelist_management_tests {
 
         let res: WhitelistedUpdatersResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters {})
+            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters { start_after: None, limit: None })
             .unwrap();
 
         assert!(res.updaters.contains(&new_updater.into_addr().to_string()));
@@ -474,7 +478,7 @@ mod whitelist_management_tests {
 
         let res: WhitelistedUpdatersResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters {})
+            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters { start_after: None, limit: None })
             .unwrap();
 
         assert!(res.updaters.contains(&new_updater.into_addr().to_string()));
@@ -531,7 +535,7 @@ mod whitelist_management_tests {
 
         let res: WhitelistedUpdatersResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters {})
+            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters { start_after: None, limit: None })
             .unwrap();
 
         assert!(!res.updaters.contains(&existing_updater.to_string()));
@@ -553,7 +557,7 @@ mod whitelist_management_tests {
 
         let res: WhitelistedUpdatersResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters {})
+            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters { start_after: None, limit: None })
             .unwrap();
 
         assert!(!res.updaters.contains(&existing_updater.to_string()));
@@ -648,7 +652,7 @@ mod token_management_tests {
 
         let res: SupportedTokensResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens {})
+            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens { start_after: None, limit: None })
             .unwrap();
 
         assert!(res.tokens.contains(&new_token.to_string()));
@@ -670,7 +674,7 @@ mod token_management_tests {
 
         let res: SupportedTokensResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens {})
+            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens { start_after: None, limit: None })
             .unwrap();
 
         assert!(res.tokens.contains(&new_token.to_string()));
@@ -727,7 +731,7 @@ mod token_management_tests {
 
         let res: SupportedTokensResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens {})
+            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens { start_after: None, limit: None })
             .unwrap();
 
         assert!(!res.tokens.contains(&existing_token.to_string()));
@@ -749,7 +753,7 @@ mod token_management_tests {
 
         let res: SupportedTokensResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens {})
+            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens { start_after: None, limit: None })
             .unwrap();
 
         assert!(!res.tokens.contains(&existing_token.to_string()));
@@ -830,7 +834,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(40000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }
             },
             &[],
         )
@@ -849,7 +853,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(41000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(41000u128, 1u128) }
             },
             &[],
         )
@@ -860,7 +864,7 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr, &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(41000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(41000u128, 1u128), res.price);
     }
 
     #[test]
@@ -873,7 +877,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(42000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(42000u128, 1u128) }
             },
             &[],
         )
@@ -884,7 +888,7 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr, &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(42000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(42000u128, 1u128), res.price);
     }
 
     #[test]
@@ -897,7 +901,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(42000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(42000u128, 1u128) }
             },
             &[],
         )
@@ -908,7 +912,7 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr, &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(42000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(42000u128, 1u128), res.price);
     }
 
     #[test]
@@ -921,7 +925,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(44000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(44000u128, 1u128) }
             },
             &[],
         )
@@ -940,7 +944,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "xrp".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(1u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(1u128, 1u128) }
             },
             &[],
         )
@@ -960,7 +964,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(41000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(41000u128, 1u128) }
             },
             &[],
         )
@@ -971,7 +975,7 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr, &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(41000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(41000u128, 1u128), res.price);
     }
 
     #[test]
@@ -984,7 +988,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(50000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(50000u128, 1u128) }
             },
             &[],
         )
@@ -996,17 +1000,17 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(40000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), res.price);
 
         // A pending update should be created
         let res: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(1, res.updates.len());
         assert_eq!("btc", res.updates[0].token_id);
-        assert_eq!(Decimal::from_ratio(50000u128, 1u128), res.updates[0].new_price);
+        assert_eq!(Decimal256::from_ratio(50000u128, 1u128), res.updates[0].new_price);
     }
 
     #[test]
@@ -1015,8 +1019,8 @@ mod price_update_tests {
 
         // Create price data for batch update
         let mut prices = HashMap::new();
-        prices.insert("btc".to_string(), TokenPriceInfo { usd: Decimal::from_ratio(41000u128, 1u128) });
-        prices.insert("eth".to_string(), TokenPriceInfo { usd: Decimal::from_ratio(2000u128, 1u128) });
+        prices.insert("btc".to_string(), TokenPriceInfo { usd: Decimal256::from_ratio(41000u128, 1u128) });
+        prices.insert("eth".to_string(), TokenPriceInfo { usd: Decimal256::from_ratio(2000u128, 1u128) });
 
         // Execute batch update
         app.execute_contract(
@@ -1035,7 +1039,7 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(41000u128, 1u128), res_btc.price);
+        assert_eq!(Decimal256::from_ratio(41000u128, 1u128), res_btc.price);
 
         // Check ETH price
         let res_eth: TokenPriceResponse = app
@@ -1043,7 +1047,7 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr, &QueryMsg::TokenPrice { token_id: "eth".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(2000u128, 1u128), res_eth.price);
+        assert_eq!(Decimal256::from_ratio(2000u128, 1u128), res_eth.price);
     }
 
     #[test]
@@ -1056,7 +1060,7 @@ mod price_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "eth".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(2000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(2000u128, 1u128) }
             },
             &[],
         )
@@ -1065,9 +1069,9 @@ mod price_update_tests {
         // Create price data for batch update
         let mut prices = HashMap::new();
         // Within threshold for BTC
-        prices.insert("btc".to_string(), TokenPriceInfo { usd: Decimal::from_ratio(41000u128, 1u128) });
+        prices.insert("btc".to_string(), TokenPriceInfo { usd: Decimal256::from_ratio(41000u128, 1u128) });
         // Beyond threshold for ETH
-        prices.insert("eth".to_string(), TokenPriceInfo { usd: Decimal::from_ratio(3000u128, 1u128) });
+        prices.insert("eth".to_string(), TokenPriceInfo { usd: Decimal256::from_ratio(3000u128, 1u128) });
 
         // Execute batch update
         app.execute_contract(
@@ -1086,7 +1090,7 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(41000u128, 1u128), res_btc.price);
+        assert_eq!(Decimal256::from_ratio(41000u128, 1u128), res_btc.price);
 
         // Check ETH price (should not update)
         let res_eth: TokenPriceResponse = app
@@ -1094,17 +1098,17 @@ mod price_update_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "eth".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(2000u128, 1u128), res_eth.price);
+        assert_eq!(Decimal256::from_ratio(2000u128, 1u128), res_eth.price);
 
         // Check pending updates
         let res: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(1, res.updates.len());
         assert_eq!("eth", res.updates[0].token_id);
-        assert_eq!(Decimal::from_ratio(3000u128, 1u128), res.updates[0].new_price);
+        assert_eq!(Decimal256::from_ratio(3000u128, 1u128), res.updates[0].new_price);
     }
 }
 
@@ -1145,7 +1149,7 @@ mod pending_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(40000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }
             },
             &[],
         )
@@ -1157,7 +1161,7 @@ mod pending_update_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(50000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(50000u128, 1u128) }
             },
             &[],
         )
@@ -1176,7 +1180,7 @@ mod pending_update_tests {
             contract_addr.clone(),
             &ExecuteMsg::ApprovePrice {
                 token_id: "btc".to_string(),
-                price: Decimal::from_ratio(50000u128, 1u128)
+                price: Decimal256::from_ratio(50000u128, 1u128)
             },
             &[],
         )
@@ -1188,12 +1192,12 @@ mod pending_update_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(50000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(50000u128, 1u128), res.price);
 
         // Check pending update was removed
         let res: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(0, res.updates.len());
@@ -1209,7 +1213,7 @@ mod pending_update_tests {
             contract_addr.clone(),
             &ExecuteMsg::ApprovePrice {
                 token_id: "btc".to_string(),
-                price: Decimal::from_ratio(50000u128, 1u128)
+                price: Decimal256::from_ratio(50000u128, 1u128)
             },
             &[],
         )
@@ -1221,7 +1225,7 @@ mod pending_update_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(50000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(50000u128, 1u128), res.price);
     }
 
     #[test]
@@ -1234,7 +1238,7 @@ mod pending_update_tests {
             contract_addr.clone(),
             &ExecuteMsg::ApprovePrice {
                 token_id: "btc".to_string(),
-                price: Decimal::from_ratio(50000u128, 1u128)
+                price: Decimal256::from_ratio(50000u128, 1u128)
             },
             &[],
         )
@@ -1253,7 +1257,7 @@ mod pending_update_tests {
             contract_addr.clone(),
             &ExecuteMsg::ApprovePrice {
                 token_id: "eth".to_string(),
-                price: Decimal::from_ratio(2000u128, 1u128)
+                price: Decimal256::from_ratio(2000u128, 1u128)
             },
             &[],
         )
@@ -1272,7 +1276,10 @@ mod pending_update_tests {
         app.execute_contract(
             admin.clone(),
             contract_addr.clone(),
-            &ExecuteMsg::RejectPrice { token_id: "btc".to_string() },
+            &ExecuteMsg::RejectPrice {
+                token_id: "btc".to_string(),
+                reason: None,
+            },
             &[],
         )
             .unwrap();
@@ -1283,12 +1290,12 @@ mod pending_update_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(40000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), res.price);
 
         // Check pending update was removed
         let res: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(0, res.updates.len());
@@ -1302,7 +1309,10 @@ mod pending_update_tests {
         app.execute_contract(
             owner.clone(),
             contract_addr.clone(),
-            &ExecuteMsg::RejectPrice { token_id: "btc".to_string() },
+            &ExecuteMsg::RejectPrice {
+                token_id: "btc".to_string(),
+                reason: None,
+            },
             &[],
         )
             .unwrap();
@@ -1310,7 +1320,7 @@ mod pending_update_tests {
         // Check pending update was removed
         let res: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(0, res.updates.len());
@@ -1324,7 +1334,10 @@ mod pending_update_tests {
         let err = app.execute_contract(
             non_admin.clone(),
             contract_addr.clone(),
-            &ExecuteMsg::RejectPrice { token_id: "btc".to_string() },
+            &ExecuteMsg::RejectPrice {
+                token_id: "btc".to_string(),
+                reason: None,
+            },
             &[],
         )
             .unwrap_err();
@@ -1340,7 +1353,10 @@ mod pending_update_tests {
         let err = app.execute_contract(
             admin.clone(),
             contract_addr.clone(),
-            &ExecuteMsg::RejectPrice { token_id: "eth".to_string() },
+            &ExecuteMsg::RejectPrice {
+                token_id: "eth".to_string(),
+                reason: None,
+            },
             &[],
         )
             .unwrap_err();
@@ -1353,7 +1369,11 @@ mod pending_update_tests {
 mod query_tests {
     use cosmwasm_std::Addr;
     use super::*;
-    use crate::msg::SupportedTokensResponse;
+    use crate::msg::{MigrateMsg, PriceStatus, SupportedTokensResponse};
+    use crate::state::TOKENS;
+    use cosmwasm_schema::cw_serde;
+    use cw_storage_plus::Map;
+    use crate::TokenPricesResponse;
     use thiserror::__private::AsDynError;
 
     fn setup_with_prices() -> (App, String) {
@@ -1388,7 +1408,7 @@ mod query_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(40000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }
             },
             &[],
         )
@@ -1399,7 +1419,7 @@ mod query_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "eth".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(2000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(2000u128, 1u128) }
             },
             &[],
         )
@@ -1411,7 +1431,7 @@ mod query_tests {
             Addr::unchecked(contract_addr.clone()),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(50000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(50000u128, 1u128) }
             },
             &[],
         )
@@ -1444,7 +1464,7 @@ mod query_tests {
             .unwrap();
 
         assert_eq!("btc", res.token_id);
-        assert_eq!(Decimal::from_ratio(40000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), res.price);
     }
 
     #[test]
@@ -1471,20 +1491,109 @@ mod query_tests {
         assert!(err.to_string().contains("Token xrp not supported"));
     }
 
+    #[test]
+    fn query_token_prices_reports_status_per_token_in_request_order() {
+        let (mut app, contract_addr) = setup_with_prices();
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(120);
+        });
+
+        // Refresh ETH so only BTC is older than the max age
+        app.execute_contract(
+            "updater".into_addr(),
+            Addr::unchecked(contract_addr.clone()),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "eth".to_string(),
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(2010u128, 1u128) }
+            },
+            &[],
+        )
+            .unwrap();
+
+        let res: TokenPricesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::TokenPrices {
+                token_ids: vec!["xrp".to_string(), "eth".to_string(), "sol".to_string(), "btc".to_string()],
+                max_age_seconds: Some(60),
+            })
+            .unwrap();
+
+        assert_eq!(4, res.prices.len());
+
+        assert_eq!("xrp", res.prices[0].token_id);
+        assert_eq!(PriceStatus::Unsupported, res.prices[0].status);
+        assert_eq!(None, res.prices[0].price);
+
+        assert_eq!("eth", res.prices[1].token_id);
+        assert_eq!(PriceStatus::Ok, res.prices[1].status);
+        assert_eq!(Some(Decimal256::from_ratio(2010u128, 1u128)), res.prices[1].price);
+
+        assert_eq!("sol", res.prices[2].token_id);
+        assert_eq!(PriceStatus::Missing, res.prices[2].status);
+
+        assert_eq!("btc", res.prices[3].token_id);
+        assert_eq!(PriceStatus::Stale, res.prices[3].status);
+        assert_eq!(Some(Decimal256::from_ratio(40000u128, 1u128)), res.prices[3].price);
+    }
+
+    #[test]
+    fn query_token_prices_without_max_age_never_reports_stale() {
+        let (mut app, contract_addr) = setup_with_prices();
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(86_400);
+        });
+
+        let res: TokenPricesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::TokenPrices {
+                token_ids: vec!["btc".to_string()],
+                max_age_seconds: None,
+            })
+            .unwrap();
+
+        assert_eq!(PriceStatus::Ok, res.prices[0].status);
+    }
+
     #[test]
     fn query_all_prices_returns_correct_list() {
         let (app, contract_addr) = setup_with_prices();
 
         let res: AllPricesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::AllPrices {})
+            .query_wasm_smart(contract_addr, &QueryMsg::AllPrices { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(2, res.prices.len());
-        assert!(res.prices.contains_key("btc"));
-        assert!(res.prices.contains_key("eth"));
-        assert_eq!(Decimal::from_ratio(40000u128, 1u128), res.prices.get("btc").unwrap().usd);
-        assert_eq!(Decimal::from_ratio(2000u128, 1u128), res.prices.get("eth").unwrap().usd);
+        assert_eq!("btc", res.prices[0].token_id);
+        assert_eq!("eth", res.prices[1].token_id);
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), res.prices[0].price);
+        assert_eq!(Decimal256::from_ratio(2000u128, 1u128), res.prices[1].price);
+        assert_eq!(None, res.next_start_after);
+    }
+
+    #[test]
+    fn query_all_prices_paginates_in_token_order() {
+        let (app, contract_addr) = setup_with_prices();
+
+        let page: AllPricesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::AllPrices { start_after: None, limit: Some(1) })
+            .unwrap();
+
+        assert_eq!(1, page.prices.len());
+        assert_eq!("btc", page.prices[0].token_id);
+        assert_eq!(Some("btc".to_string()), page.next_start_after);
+
+        let page: AllPricesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::AllPrices { start_after: page.next_start_after, limit: Some(1) })
+            .unwrap();
+
+        assert_eq!(1, page.prices.len());
+        assert_eq!("eth", page.prices[0].token_id);
+        assert_eq!(None, page.next_start_after);
     }
 
     #[test]
@@ -1505,7 +1614,7 @@ mod query_tests {
         println!("History: {:?}", res.history);
 
         assert_eq!(res.history.len(), 1);
-        assert_eq!(res.history[0].price, Decimal::from_ratio(40000u128, 1u128));
+        assert_eq!(res.history[0].price, Decimal256::from_ratio(40000u128, 1u128));
     }
 
     #[test]
@@ -1514,14 +1623,14 @@ mod query_tests {
 
         let res: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         println!("{:?}",res.updates);
         assert_eq!(1, res.updates.len());
         assert_eq!("btc", res.updates[0].token_id);
-        assert_eq!(Decimal::from_ratio(40000u128, 1u128), res.updates[0].current_price);
-        assert_eq!(Decimal::from_ratio(50000u128, 1u128), res.updates[0].new_price);
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), res.updates[0].current_price);
+        assert_eq!(Decimal256::from_ratio(50000u128, 1u128), res.updates[0].new_price);
     }
 
     #[test]
@@ -1540,7 +1649,7 @@ mod query_tests {
 
         let res: SupportedTokensResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens {})
+            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(2, res.tokens.len());
@@ -1549,6 +1658,99 @@ mod query_tests {
         assert!(!res.tokens.contains(&"sol".to_string()));
     }
 
+    #[test]
+    fn query_supported_tokens_paginates_and_skips_removed_tokens() {
+        let (mut app, contract_addr) = setup_with_prices();
+        let admin = "admin".into_addr();
+
+        app.execute_contract(
+            admin.clone(),
+            Addr::unchecked(contract_addr.clone()),
+            &ExecuteMsg::RemoveSupportedToken { token_id: "eth".to_string() },
+            &[],
+        )
+            .unwrap();
+
+        let page: SupportedTokensResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::SupportedTokens { start_after: None, limit: Some(1) })
+            .unwrap();
+
+        assert_eq!(vec!["btc".to_string()], page.tokens);
+        assert_eq!(Some("btc".to_string()), page.next_start_after);
+
+        let page: SupportedTokensResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::SupportedTokens { start_after: page.next_start_after, limit: Some(1) })
+            .unwrap();
+
+        assert_eq!(vec!["sol".to_string()], page.tokens);
+        assert_eq!(None, page.next_start_after);
+    }
+
+    #[test]
+    fn removed_tokens_are_deleted_and_legacy_entries_migrated() {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string(), "eth".to_string()],
+                    whitelisted_updaters: vec![],
+                },
+                &[],
+                "Oracle Contract",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        app.execute_contract(
+            owner.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::RemoveSupportedToken { token_id: "eth".to_string() },
+            &[],
+        )
+        .unwrap();
+        assert!(!TOKENS.has(app.contract_storage(&contract_addr).as_ref(), "eth"));
+
+        // The previous version kept removed tokens as unsupported
+        #[cw_serde]
+        struct LegacyTokenInfo {
+            supported: bool,
+        }
+        const LEGACY_TOKENS: Map<&str, LegacyTokenInfo> = Map::new("tokens");
+
+        let mut storage = app.contract_storage_mut(&contract_addr);
+        LEGACY_TOKENS
+            .save(storage.as_mut(), "sol", &LegacyTokenInfo { supported: false })
+            .unwrap();
+        LEGACY_TOKENS
+            .save(storage.as_mut(), "btc", &LegacyTokenInfo { supported: true })
+            .unwrap();
+        drop(storage);
+
+        app.migrate_contract(owner, contract_addr.clone(), &MigrateMsg {}, code_id)
+            .unwrap();
+
+        assert!(!TOKENS.has(app.contract_storage(&contract_addr).as_ref(), "sol"));
+        // Supported entries are rewritten without the flag
+        TOKENS
+            .load(app.contract_storage(&contract_addr).as_ref(), "btc")
+            .unwrap();
+        let res: SupportedTokensResponse = app
+            .wrap()
+            .query_wasm_smart(&contract_addr, &QueryMsg::SupportedTokens { start_after: None, limit: None })
+            .unwrap();
+        assert_eq!(vec!["btc".to_string()], res.tokens);
+    }
+
     #[test]
     fn query_whitelisted_updaters_returns_correct_list() {
         let (mut app, contract_addr) = setup_with_prices();
@@ -1565,13 +1767,44 @@ mod query_tests {
 
         let res: WhitelistedUpdatersResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters {})
+            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(2, res.updaters.len());
         assert!(res.updaters.contains(&"updater".into_addr().to_string()));
         assert!(res.updaters.contains(&"new_updater".into_addr().to_string()));
     }
+
+    #[test]
+    fn query_whitelisted_updaters_paginates() {
+        let (mut app, contract_addr) = setup_with_prices();
+        let admin = "admin".into_addr();
+
+        app.execute_contract(
+            admin.clone(),
+            Addr::unchecked(contract_addr.clone()),
+            &ExecuteMsg::AddWhitelistedUpdater { updater: "new_updater".into_addr().to_string() },
+            &[],
+        )
+            .unwrap();
+
+        let first: WhitelistedUpdatersResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::WhitelistedUpdaters { start_after: None, limit: Some(1) })
+            .unwrap();
+
+        assert_eq!(1, first.updaters.len());
+        assert_eq!(Some(first.updaters[0].clone()), first.next_start_after);
+
+        let second: WhitelistedUpdatersResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::WhitelistedUpdaters { start_after: first.next_start_after, limit: Some(1) })
+            .unwrap();
+
+        assert_eq!(1, second.updaters.len());
+        assert_ne!(first.updaters[0], second.updaters[0]);
+        assert_eq!(None, second.next_start_after);
+    }
 }
 
 mod integration_scenario_tests {
@@ -1611,7 +1844,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(40000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }
             },
             &[],
         )
@@ -1623,7 +1856,7 @@ mod integration_scenario_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(40000u128, 1u128), res.price);
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), res.price);
 
         // Step 2: Update beyond threshold creates pending update
         app.execute_contract(
@@ -1631,7 +1864,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(50000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(50000u128, 1u128) }
             },
             &[],
         )
@@ -1640,19 +1873,19 @@ mod integration_scenario_tests {
         // Verify pending update created and price unchanged
         let pending: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr.clone(), &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(1, pending.updates.len());
         assert_eq!("btc", pending.updates[0].token_id);
-        assert_eq!(Decimal::from_ratio(50000u128, 1u128), pending.updates[0].new_price);
+        assert_eq!(Decimal256::from_ratio(50000u128, 1u128), pending.updates[0].new_price);
 
         let price: TokenPriceResponse = app
             .wrap()
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(40000u128, 1u128), price.price);
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), price.price);
 
         // Step 3: Admin approves the pending update
         app.execute_contract(
@@ -1660,7 +1893,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::ApprovePrice {
                 token_id: "btc".to_string(),
-                price: Decimal::from_ratio(50000u128, 1u128)
+                price: Decimal256::from_ratio(50000u128, 1u128)
             },
             &[],
         )
@@ -1672,11 +1905,11 @@ mod integration_scenario_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(50000u128, 1u128), price.price);
+        assert_eq!(Decimal256::from_ratio(50000u128, 1u128), price.price);
 
         let pending: PendingUpdatesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates {})
+            .query_wasm_smart(contract_addr, &QueryMsg::PendingUpdates { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(0, pending.updates.len());
@@ -1709,12 +1942,12 @@ mod integration_scenario_tests {
             .unwrap();
 
         // Update price multiple times
-        let prices = vec![
-            Decimal::from_ratio(2000u128, 1u128),
-            Decimal::from_ratio(2100u128, 1u128),
-            Decimal::from_ratio(2050u128, 1u128),
-            Decimal::from_ratio(2200u128, 1u128),
-            Decimal::from_ratio(2150u128, 1u128),
+        let prices = [
+            Decimal256::from_ratio(2000u128, 1u128),
+            Decimal256::from_ratio(2100u128, 1u128),
+            Decimal256::from_ratio(2050u128, 1u128),
+            Decimal256::from_ratio(2200u128, 1u128),
+            Decimal256::from_ratio(2150u128, 1u128),
         ];
 
         for price in prices.iter() {
@@ -1750,11 +1983,11 @@ mod integration_scenario_tests {
         assert_eq!(5, history.history.len());
 
         // Verify prices in reverse order (most recent first)
-        assert_eq!(Decimal::from_ratio(2150u128, 1u128), history.history[0].price);
-        assert_eq!(Decimal::from_ratio(2200u128, 1u128), history.history[1].price);
-        assert_eq!(Decimal::from_ratio(2050u128, 1u128), history.history[2].price);
-        assert_eq!(Decimal::from_ratio(2100u128, 1u128), history.history[3].price);
-        assert_eq!(Decimal::from_ratio(2000u128, 1u128), history.history[4].price);
+        assert_eq!(Decimal256::from_ratio(2150u128, 1u128), history.history[0].price);
+        assert_eq!(Decimal256::from_ratio(2200u128, 1u128), history.history[1].price);
+        assert_eq!(Decimal256::from_ratio(2050u128, 1u128), history.history[2].price);
+        assert_eq!(Decimal256::from_ratio(2100u128, 1u128), history.history[3].price);
+        assert_eq!(Decimal256::from_ratio(2000u128, 1u128), history.history[4].price);
 
         // Test with limit
         let limited_history: PriceHistoryResponse = app
@@ -1768,7 +2001,7 @@ mod integration_scenario_tests {
             .unwrap();
 
         assert_eq!(3, limited_history.history.len());
-        assert_eq!(Decimal::from_ratio(2150u128, 1u128), limited_history.history[0].price);
+        assert_eq!(Decimal256::from_ratio(2150u128, 1u128), limited_history.history[0].price);
     }
 
     #[test]
@@ -1803,7 +2036,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(40000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }
             },
             &[],
         )
@@ -1821,7 +2054,7 @@ mod integration_scenario_tests {
         // Verify supported tokens list
         let tokens: SupportedTokensResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr.clone(), &QueryMsg::SupportedTokens {})
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::SupportedTokens { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(2, tokens.tokens.len());
@@ -1834,7 +2067,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "eth".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(2000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(2000u128, 1u128) }
             },
             &[],
         )
@@ -1843,12 +2076,12 @@ mod integration_scenario_tests {
         // Check all prices
         let prices: AllPricesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr.clone(), &QueryMsg::AllPrices {})
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::AllPrices { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(2, prices.prices.len());
-        assert!(prices.prices.contains_key("btc"));
-        assert!(prices.prices.contains_key("eth"));
+        assert!(prices.prices.iter().any(|p| p.token_id == "btc"));
+        assert!(prices.prices.iter().any(|p| p.token_id == "eth"));
 
         // Remove a token
         app.execute_contract(
@@ -1862,7 +2095,7 @@ mod integration_scenario_tests {
         // Verify supported tokens list
         let tokens: SupportedTokensResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr.clone(), &QueryMsg::SupportedTokens {})
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::SupportedTokens { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(1, tokens.tokens.len());
@@ -1872,7 +2105,7 @@ mod integration_scenario_tests {
         // Check all prices
         let prices: AllPricesResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr.clone(), &QueryMsg::AllPrices {})
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::AllPrices { start_after: None, limit: None })
             .unwrap();
 
         println!("{:?}",prices);
@@ -1919,7 +2152,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(40000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }
             },
             &[],
         )
@@ -1931,7 +2164,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(41000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(41000u128, 1u128) }
             },
             &[],
         )
@@ -1951,7 +2184,7 @@ mod integration_scenario_tests {
         // Verify whitelisted updaters
         let updaters: WhitelistedUpdatersResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr.clone(), &QueryMsg::WhitelistedUpdaters {})
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::WhitelistedUpdaters { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(2, updaters.updaters.len());
@@ -1964,7 +2197,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(41000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(41000u128, 1u128) }
             },
             &[],
         )
@@ -1976,7 +2209,7 @@ mod integration_scenario_tests {
             .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(41000u128, 1u128), price.price);
+        assert_eq!(Decimal256::from_ratio(41000u128, 1u128), price.price);
 
         // Remove updater1 from whitelist
         app.execute_contract(
@@ -1990,7 +2223,7 @@ mod integration_scenario_tests {
         // Verify whitelisted updaters
         let updaters: WhitelistedUpdatersResponse = app
             .wrap()
-            .query_wasm_smart(contract_addr.clone(), &QueryMsg::WhitelistedUpdaters {})
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::WhitelistedUpdaters { start_after: None, limit: None })
             .unwrap();
 
         assert_eq!(1, updaters.updaters.len());
@@ -2003,7 +2236,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(42000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(42000u128, 1u128) }
             },
             &[],
         )
@@ -2017,7 +2250,7 @@ mod integration_scenario_tests {
             contract_addr.clone(),
             &ExecuteMsg::UpdateSinglePrice {
                 token_id: "btc".to_string(),
-                price_info: TokenPriceInfo { usd: Decimal::from_ratio(42000u128, 1u128) }
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(42000u128, 1u128) }
             },
             &[],
         )
@@ -2029,7 +2262,5531 @@ mod integration_scenario_tests {
             .query_wasm_smart(contract_addr, &QueryMsg::TokenPrice { token_id: "btc".to_string() })
             .unwrap();
 
-        assert_eq!(Decimal::from_ratio(42000u128, 1u128), price.price);
+        assert_eq!(Decimal256::from_ratio(42000u128, 1u128), price.price);
+    }
+}
+
+
+mod price_stats_tests {
+    use super::*;
+    use crate::msg::{PriceStatsResponse, Submitter};
+    use crate::state::{PriceRecord, PRICE_HISTORY};
+    use cosmwasm_std::Addr;
+
+    fn setup_with_history(prices: &[u128]) -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+        let updater = "updater".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(20),
+                    supported_tokens: vec!["eth".to_string()],
+                    whitelisted_updaters: vec![updater.to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        for price in prices {
+            app.update_block(|block| {
+                block.time = block.time.plus_seconds(60);
+            });
+
+            app.execute_contract(
+                updater.clone(),
+                contract_addr.clone(),
+                &ExecuteMsg::UpdateSinglePrice {
+                    token_id: "eth".to_string(),
+                    price_info: TokenPriceInfo { usd: Decimal256::from_ratio(*price, 1u128) }
+                },
+                &[],
+            )
+                .unwrap();
+        }
+
+        (app, contract_addr)
+    }
+
+    fn query_stats(app: &App, contract_addr: &Addr, window_seconds: u64) -> PriceStatsResponse {
+        app.wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::PriceStats {
+                token_id: "eth".to_string(),
+                window_seconds,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn price_stats_over_full_window() {
+        let (app, contract_addr) = setup_with_history(&[100, 110, 99, 105]);
+
+        let stats = query_stats(&app, &contract_addr, 3600);
+
+        assert_eq!(4, stats.sample_count);
+        assert!(!stats.truncated);
+        assert_eq!(Some(Decimal256::from_ratio(99u128, 1u128)), stats.min);
+        assert_eq!(Some(Decimal256::from_ratio(110u128, 1u128)), stats.max);
+        assert_eq!(Some(Decimal256::from_ratio(1035u128, 10u128)), stats.mean);
+        assert_eq!(Some(Decimal256::from_ratio(105u128, 1u128)), stats.last);
+        assert_eq!(Some(Decimal::percent(10)), stats.max_drawdown);
+
+        // Sample std dev of ln(110/100), ln(99/110), ln(105/99) is ~0.106896163878
+        let volatility = stats.volatility.unwrap();
+        let expected = Decimal::from_ratio(106_896_163_878u128, 1_000_000_000_000u128);
+        assert!(volatility.abs_diff(expected) < Decimal::from_ratio(1u128, 1_000_000_000u128));
+    }
+
+    #[test]
+    fn price_stats_only_uses_samples_inside_window() {
+        let (app, contract_addr) = setup_with_history(&[100, 110, 99, 105]);
+
+        // Samples are 60s apart and the last one is at the current block time
+        let stats = query_stats(&app, &contract_addr, 60);
+
+        assert_eq!(2, stats.sample_count);
+        assert_eq!(Some(Decimal256::from_ratio(99u128, 1u128)), stats.min);
+        assert_eq!(Some(Decimal256::from_ratio(105u128, 1u128)), stats.max);
+        assert_eq!(Some(Decimal::zero()), stats.max_drawdown);
+        // A single return is not enough for a standard deviation
+        assert_eq!(None, stats.volatility);
+    }
+
+    #[test]
+    fn price_stats_for_empty_window() {
+        let (mut app, contract_addr) = setup_with_history(&[100]);
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(3600);
+        });
+
+        let stats = query_stats(&app, &contract_addr, 60);
+
+        assert_eq!(0, stats.sample_count);
+        assert!(!stats.truncated);
+        assert_eq!(None, stats.min);
+        assert_eq!(None, stats.mean);
+        assert_eq!(None, stats.last);
+        assert_eq!(None, stats.volatility);
+        assert_eq!(None, stats.max_drawdown);
+    }
+
+    #[test]
+    fn price_stats_reports_capped_windows() {
+        let (mut app, contract_addr) = setup_with_history(&[100]);
+        let now = app.block_info().time.seconds();
+
+        // One sample per second going back further than the cap reaches
+        let mut storage = app.contract_storage_mut(&contract_addr);
+        for age in 1..=1000u64 {
+            PRICE_HISTORY
+                .save(
+                    storage.as_mut(),
+                    ("eth", now - age),
+                    &PriceRecord {
+                        price: Decimal256::from_ratio(200u128, 1u128),
+                        submitter: Submitter::Governance {},
+                    },
+                )
+                .unwrap();
+        }
+        drop(storage);
+
+        let stats = query_stats(&app, &contract_addr, 3600);
+        assert_eq!(1000, stats.sample_count);
+        assert!(stats.truncated);
+        assert_eq!(Some(Decimal256::from_ratio(100u128, 1u128)), stats.last);
+
+        let stats = query_stats(&app, &contract_addr, 999);
+        assert_eq!(1000, stats.sample_count);
+        assert!(!stats.truncated);
     }
 }
 
+mod ema_tests {
+    use super::*;
+    use crate::msg::EmaResponse;
+    use cosmwasm_std::{Addr, StdResult};
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+        let updater = "updater".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(100),
+                    supported_tokens: vec!["eth".to_string()],
+                    whitelisted_updaters: vec![updater.to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            owner,
+            contract_addr.clone(),
+            &ExecuteMsg::SetEmaPeriods { half_lives: vec![60] },
+            &[],
+        )
+            .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn update_price(app: &mut App, contract_addr: &Addr, price: u128) {
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "eth".to_string(),
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(price, 1u128) }
+            },
+            &[],
+        )
+            .unwrap();
+    }
+
+    fn query_ema(app: &App, contract_addr: &Addr) -> EmaResponse {
+        app.wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::Ema { token_id: "eth".to_string(), period: 60 })
+            .unwrap()
+    }
+
+    #[test]
+    fn first_price_seeds_ema() {
+        let (mut app, contract_addr) = setup();
+
+        update_price(&mut app, &contract_addr, 100);
+
+        let ema = query_ema(&app, &contract_addr);
+        assert_eq!(Decimal256::from_ratio(100u128, 1u128), ema.value);
+        assert_eq!(app.block_info().time.seconds(), ema.last_updated);
+    }
+
+    #[test]
+    fn ema_decays_by_elapsed_time() {
+        let (mut app, contract_addr) = setup();
+
+        update_price(&mut app, &contract_addr, 100);
+
+        // One full half-life: the old value keeps exactly half its weight
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(60);
+        });
+        update_price(&mut app, &contract_addr, 200);
+        assert_eq!(Decimal256::from_ratio(150u128, 1u128), query_ema(&app, &contract_addr).value);
+
+        // Half a half-life: the old value keeps 2^-0.5 of its weight
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(30);
+        });
+        update_price(&mut app, &contract_addr, 300);
+        let value = query_ema(&app, &contract_addr).value;
+        let expected = Decimal256::from_ratio(193_933_982_822u128, 1_000_000_000u128);
+        assert!(value.abs_diff(expected) < Decimal256::from_ratio(1u128, 1_000_000u128));
+    }
+
+    #[test]
+    fn approved_price_updates_ema() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetDeviationThreshold { threshold: Decimal::percent(5) },
+            &[],
+        )
+            .unwrap();
+
+        update_price(&mut app, &contract_addr, 100);
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(60);
+        });
+        update_price(&mut app, &contract_addr, 200);
+
+        // Pending update does not touch the EMA
+        assert_eq!(Decimal256::from_ratio(100u128, 1u128), query_ema(&app, &contract_addr).value);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ApprovePrice { token_id: "eth".to_string(), price: Decimal256::from_ratio(200u128, 1u128) },
+            &[],
+        )
+            .unwrap();
+
+        assert_eq!(Decimal256::from_ratio(150u128, 1u128), query_ema(&app, &contract_addr).value);
+    }
+
+    #[test]
+    fn querying_unconfigured_period_fails() {
+        let (mut app, contract_addr) = setup();
+
+        update_price(&mut app, &contract_addr, 100);
+
+        let err = app
+            .wrap()
+            .query_wasm_smart::<EmaResponse>(contract_addr, &QueryMsg::Ema { token_id: "eth".to_string(), period: 3600 })
+            .unwrap_err();
+
+        assert!(err.to_string().contains("EMA period 3600 not configured"));
+    }
+
+    #[test]
+    fn set_ema_periods_validation() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "updater".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetEmaPeriods { half_lives: vec![300] },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Unauthorized"));
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetEmaPeriods { half_lives: vec![0] },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Half-life must be greater than zero"));
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetEmaPeriods { half_lives: vec![3600, 60, 3600] },
+            &[],
+        )
+            .unwrap();
+
+        let config: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(vec![60, 3600], config.ema_half_lives);
+    }
+    #[test]
+    fn referenced_half_life_cannot_be_removed() {
+        let (mut app, contract_addr) = setup();
+        update_price(&mut app, &contract_addr, 100);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetDeviationReference {
+                token_id: "eth".to_string(),
+                reference: crate::msg::DeviationReference::Ema { period: 60 },
+            },
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetEmaPeriods { half_lives: vec![120] },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            crate::ContractError::InvalidEmaPeriods(
+                "Half-life 60 is the deviation reference of token eth".to_string()
+            ),
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn removed_half_life_drops_its_values() {
+        let (mut app, contract_addr) = setup();
+        update_price(&mut app, &contract_addr, 100);
+
+        for half_lives in [vec![120], vec![60, 120]] {
+            app.execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetEmaPeriods { half_lives },
+                &[],
+            )
+            .unwrap();
+        }
+
+        // The re-added half-life has no value until the next price
+        let res: StdResult<EmaResponse> = app.wrap().query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::Ema { token_id: "eth".to_string(), period: 60 },
+        );
+        assert!(res.is_err());
+
+        update_price(&mut app, &contract_addr, 110);
+        assert_eq!(Decimal256::from_ratio(110u128, 1u128), query_ema(&app, &contract_addr).value);
+    }
+
+    #[test]
+    fn re_added_token_starts_without_history() {
+        let (mut app, contract_addr) = setup();
+        update_price(&mut app, &contract_addr, 100);
+
+        for msg in [
+            ExecuteMsg::RemoveSupportedToken {
+                token_id: "eth".to_string(),
+            },
+            ExecuteMsg::AddSupportedToken {
+                token_id: "eth".to_string(),
+            },
+        ] {
+            app.execute_contract("owner".into_addr(), contract_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        // Neither the old history nor the old averages come back
+        let history: PriceHistoryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr.clone(),
+                &QueryMsg::PriceHistory {
+                    token_id: "eth".to_string(),
+                    start_time: None,
+                    end_time: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(history.history.is_empty());
+
+        let res: StdResult<EmaResponse> = app.wrap().query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::Ema {
+                token_id: "eth".to_string(),
+                period: 60,
+            },
+        );
+        assert!(res.is_err());
+
+        update_price(&mut app, &contract_addr, 200);
+        assert_eq!(
+            Decimal256::from_ratio(200u128, 1u128),
+            query_ema(&app, &contract_addr).value
+        );
+    }
+}
+
+mod deviation_reference_tests {
+    use super::*;
+    use crate::msg::{DeviationReference, TwapResponse};
+    use cosmwasm_std::Addr;
+
+    fn setup(threshold: Decimal) -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+        let updater = "updater".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: threshold,
+                    supported_tokens: vec!["eth".to_string()],
+                    whitelisted_updaters: vec![updater.to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn update_price(app: &mut App, contract_addr: &Addr, price: Decimal256) {
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "eth".to_string(),
+                price_info: TokenPriceInfo { usd: price }
+            },
+            &[],
+        )
+            .unwrap();
+    }
+
+    fn advance(app: &mut App, seconds: u64) {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(seconds);
+        });
+    }
+
+    fn set_reference(app: &mut App, contract_addr: &Addr, reference: DeviationReference) {
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetDeviationReference { token_id: "eth".to_string(), reference },
+            &[],
+        )
+            .unwrap();
+    }
+
+    fn pending_count(app: &App, contract_addr: &Addr) -> usize {
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::PendingUpdates { start_after: None, limit: None })
+            .unwrap();
+        res.updates.len()
+    }
+
+    // Walk the price up by 4% per minute, staying under a 5% step threshold
+    fn walk_price(app: &mut App, contract_addr: &Addr) {
+        update_price(app, contract_addr, Decimal256::from_ratio(100u128, 1u128));
+        advance(app, 60);
+        update_price(app, contract_addr, Decimal256::from_ratio(104u128, 1u128));
+        advance(app, 60);
+        update_price(app, contract_addr, Decimal256::from_ratio(10816u128, 100u128));
+    }
+
+    #[test]
+    fn twap_query_weights_prices_by_time() {
+        let (mut app, contract_addr) = setup(Decimal::percent(100));
+
+        update_price(&mut app, &contract_addr, Decimal256::from_ratio(100u128, 1u128));
+        advance(&mut app, 60);
+        update_price(&mut app, &contract_addr, Decimal256::from_ratio(200u128, 1u128));
+        advance(&mut app, 60);
+
+        let query = |window_seconds: u64| -> Decimal256 {
+            let res: TwapResponse = app
+                .wrap()
+                .query_wasm_smart(contract_addr.clone(), &QueryMsg::Twap { token_id: "eth".to_string(), window_seconds })
+                .unwrap();
+            res.twap
+        };
+
+        assert_eq!(Decimal256::from_ratio(150u128, 1u128), query(120));
+        assert_eq!(Decimal256::from_ratio(200u128, 1u128), query(60));
+        // Window reaching before the first sample only averages the known period
+        assert_eq!(Decimal256::from_ratio(150u128, 1u128), query(10_000));
+    }
+
+    #[test]
+    fn last_price_reference_lets_small_steps_through() {
+        let (mut app, contract_addr) = setup(Decimal::percent(5));
+
+        walk_price(&mut app, &contract_addr);
+
+        assert_eq!(0, pending_count(&app, &contract_addr));
+    }
+
+    #[test]
+    fn twap_reference_catches_cumulative_drift() {
+        let (mut app, contract_addr) = setup(Decimal::percent(5));
+        set_reference(&mut app, &contract_addr, DeviationReference::Twap { window_seconds: 600 });
+
+        walk_price(&mut app, &contract_addr);
+
+        // 108.16 is 4% above the last price but ~6% above the 102 TWAP
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::PendingUpdates { start_after: None, limit: None })
+            .unwrap();
+        assert_eq!(1, res.updates.len());
+        assert_eq!(Decimal256::from_ratio(104u128, 1u128), res.updates[0].current_price);
+        assert_eq!(Decimal256::from_ratio(10816u128, 100u128), res.updates[0].new_price);
+        assert_eq!(Decimal256::from_ratio(616u128, 10200u128), res.updates[0].percent_change);
+    }
+
+    #[test]
+    fn ema_reference_catches_cumulative_drift() {
+        let (mut app, contract_addr) = setup(Decimal::percent(5));
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetEmaPeriods { half_lives: vec![600] },
+            &[],
+        )
+            .unwrap();
+        set_reference(&mut app, &contract_addr, DeviationReference::Ema { period: 600 });
+
+        walk_price(&mut app, &contract_addr);
+
+        assert_eq!(1, pending_count(&app, &contract_addr));
+    }
+
+    #[test]
+    fn set_deviation_reference_validation() {
+        let (mut app, contract_addr) = setup(Decimal::percent(5));
+
+        let err = app
+            .execute_contract(
+                "updater".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetDeviationReference {
+                    token_id: "eth".to_string(),
+                    reference: DeviationReference::LastPrice {},
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Unauthorized"));
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetDeviationReference {
+                    token_id: "btc".to_string(),
+                    reference: DeviationReference::LastPrice {},
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Token btc not supported"));
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetDeviationReference {
+                    token_id: "eth".to_string(),
+                    reference: DeviationReference::Ema { period: 600 },
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("EMA period 600 not configured"));
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr,
+                &ExecuteMsg::SetDeviationReference {
+                    token_id: "eth".to_string(),
+                    reference: DeviationReference::Twap { window_seconds: 0 },
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("TWAP window must be greater than zero"));
+    }
+}
+
+mod deviation_threshold_tests {
+    use super::*;
+    use crate::msg::{DeviationReference, DeviationThresholds, TimeScaling, TokenConfigResponse};
+    use cosmwasm_std::Addr;
+
+    fn setup(thresholds: DeviationThresholds) -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+        let updater = "updater".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["eth".to_string()],
+                    whitelisted_updaters: vec![updater.to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            owner,
+            contract_addr.clone(),
+            &ExecuteMsg::SetTokenDeviationThresholds { token_id: "eth".to_string(), thresholds: Some(thresholds) },
+            &[],
+        )
+            .unwrap();
+
+        update_price(&mut app, &contract_addr, 100);
+
+        (app, contract_addr)
+    }
+
+    fn update_price(app: &mut App, contract_addr: &Addr, price: u128) {
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "eth".to_string(),
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(price, 1u128) }
+            },
+            &[],
+        )
+            .unwrap();
+    }
+
+    fn current_price(app: &App, contract_addr: &Addr) -> Decimal256 {
+        let res: TokenPriceResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "eth".to_string() })
+            .unwrap();
+        res.price
+    }
+
+    fn advance(app: &mut App, seconds: u64) {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(seconds);
+        });
+    }
+
+    #[test]
+    fn drops_use_down_threshold_and_rises_use_up_threshold() {
+        let (mut app, contract_addr) = setup(DeviationThresholds {
+            up: Decimal::percent(10),
+            down: Decimal::percent(3),
+            time_scaling: None,
+        });
+
+        // +8% is within the 10% up threshold
+        update_price(&mut app, &contract_addr, 108);
+        assert_eq!(Decimal256::from_ratio(108u128, 1u128), current_price(&app, &contract_addr));
+
+        // -3.7% exceeds the 3% down threshold
+        update_price(&mut app, &contract_addr, 104);
+        assert_eq!(Decimal256::from_ratio(108u128, 1u128), current_price(&app, &contract_addr));
+    }
+
+    #[test]
+    fn threshold_scales_with_time_since_last_update() {
+        let (mut app, contract_addr) = setup(DeviationThresholds {
+            up: Decimal::percent(5),
+            down: Decimal::percent(5),
+            time_scaling: Some(TimeScaling {
+                reference_seconds: 3600,
+                min_multiplier: Decimal::percent(10),
+                max_multiplier: Decimal::percent(300),
+            }),
+        });
+
+        // After 6 seconds the threshold is clamped to 0.5%, so +1% goes pending
+        advance(&mut app, 6);
+        update_price(&mut app, &contract_addr, 101);
+        assert_eq!(Decimal256::from_ratio(100u128, 1u128), current_price(&app, &contract_addr));
+
+        // After one reference period the base 5% applies
+        advance(&mut app, 3594);
+        update_price(&mut app, &contract_addr, 104);
+        assert_eq!(Decimal256::from_ratio(104u128, 1u128), current_price(&app, &contract_addr));
+
+        // After four reference periods the threshold doubles to 10%
+        advance(&mut app, 4 * 3600);
+        update_price(&mut app, &contract_addr, 113);
+        assert_eq!(Decimal256::from_ratio(113u128, 1u128), current_price(&app, &contract_addr));
+    }
+
+    #[test]
+    fn token_config_reports_thresholds_and_can_be_cleared() {
+        let thresholds = DeviationThresholds {
+            up: Decimal::percent(10),
+            down: Decimal::percent(3),
+            time_scaling: None,
+        };
+        let (mut app, contract_addr) = setup(thresholds.clone());
+
+        let res: TokenConfigResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenConfig { token_id: "eth".to_string() })
+            .unwrap();
+        assert_eq!(DeviationReference::LastPrice {}, res.deviation_reference);
+        assert_eq!(Some(thresholds), res.deviation_thresholds);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetTokenDeviationThresholds { token_id: "eth".to_string(), thresholds: None },
+            &[],
+        )
+            .unwrap();
+
+        // Back on the global symmetric 5%, a -4% move applies directly
+        update_price(&mut app, &contract_addr, 96);
+        assert_eq!(Decimal256::from_ratio(96u128, 1u128), current_price(&app, &contract_addr));
+    }
+
+    #[test]
+    fn invalid_thresholds_are_rejected() {
+        let (mut app, contract_addr) = setup(DeviationThresholds {
+            up: Decimal::percent(5),
+            down: Decimal::percent(5),
+            time_scaling: None,
+        });
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetTokenDeviationThresholds {
+                    token_id: "eth".to_string(),
+                    thresholds: Some(DeviationThresholds {
+                        up: Decimal::zero(),
+                        down: Decimal::percent(5),
+                        time_scaling: None,
+                    }),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Threshold must be between"));
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr,
+                &ExecuteMsg::SetTokenDeviationThresholds {
+                    token_id: "eth".to_string(),
+                    thresholds: Some(DeviationThresholds {
+                        up: Decimal::percent(5),
+                        down: Decimal::percent(5),
+                        time_scaling: Some(TimeScaling {
+                            reference_seconds: 60,
+                            min_multiplier: Decimal::percent(200),
+                            max_multiplier: Decimal::percent(100),
+                        }),
+                    }),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("0 < min <= max"));
+    }
+}
+
+mod querier_tests {
+    use super::*;
+    use crate::helpers::{OracleContract, OracleQuerier};
+    use crate::msg::PriceStatus;
+    use cosmwasm_std::{Addr, Uint128};
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+        let updater = "updater".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string(), "eth".to_string()],
+                    whitelisted_updaters: vec![updater.to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        let oracle = OracleContract(contract_addr.clone());
+        let prices = HashMap::from([
+            ("btc".to_string(), Decimal::from_ratio(40000u128, 1u128)),
+            ("eth".to_string(), Decimal::from_ratio(2000u128, 1u128)),
+        ]);
+        app.execute(updater, oracle.update_prices(prices).unwrap()).unwrap();
+
+        (app, contract_addr)
+    }
+
+    #[test]
+    fn querier_reads_prices_and_converts() {
+        let (app, contract_addr) = setup();
+        let wrapper = app.wrap();
+        let oracle = OracleQuerier::new(&wrapper, contract_addr);
+
+        assert_eq!(Decimal256::from_ratio(40000u128, 1u128), oracle.price("btc").unwrap().price);
+        assert_eq!(Decimal256::from_ratio(2000u128, 1u128), oracle.twap("eth", 600).unwrap());
+
+        // 3 BTC at 40000 is worth 60 ETH at 2000
+        assert_eq!(Uint128::new(60), oracle.convert(Uint128::new(3), "btc", "eth").unwrap());
+
+        let prices = oracle.prices(vec!["eth".to_string(), "sol".to_string()]).unwrap();
+        assert_eq!(PriceStatus::Ok, prices[0].status);
+        assert_eq!(PriceStatus::Unsupported, prices[1].status);
+    }
+
+    #[test]
+    fn querier_enforces_max_age() {
+        let (mut app, contract_addr) = setup();
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(120);
+        });
+
+        let block_time = app.block_info().time;
+        let wrapper = app.wrap();
+        let oracle = OracleContract(contract_addr).querier(&wrapper);
+
+        assert!(oracle.price("btc").is_ok());
+
+        let oracle = oracle.with_max_age(block_time, 60);
+        let err = oracle.price("btc").unwrap_err();
+        assert!(err.to_string().contains("Price for token btc is stale"));
+
+        let err = oracle.convert(Uint128::new(1), "btc", "eth").unwrap_err();
+        assert!(err.to_string().contains("is stale"));
+
+        let err = oracle.twap("eth", 600).unwrap_err();
+        assert!(err.to_string().contains("Price for token eth is stale"));
+
+        let prices = oracle.prices(vec!["btc".to_string()]).unwrap();
+        assert_eq!(PriceStatus::Stale, prices[0].status);
+    }
+
+    #[test]
+    fn contract_helper_builds_execute_messages() {
+        let (mut app, contract_addr) = setup();
+        let oracle = OracleContract(contract_addr.clone());
+
+        app.execute(
+            "updater".into_addr(),
+            oracle.update_single_price("btc", Decimal::from_ratio(41000u128, 1u128)).unwrap(),
+        )
+            .unwrap();
+
+        app.execute(
+            "owner".into_addr(),
+            oracle.call(ExecuteMsg::AddSupportedToken { token_id: "sol".to_string() }).unwrap(),
+        )
+            .unwrap();
+
+        let wrapper = app.wrap();
+        let querier = oracle.querier(&wrapper);
+        assert_eq!(Decimal256::from_ratio(41000u128, 1u128), querier.price("btc").unwrap().price);
+        assert_eq!(PriceStatus::Missing, querier.prices(vec!["sol".to_string()]).unwrap()[0].status);
+    }
+}
+
+mod mock_tests {
+    use super::*;
+    use crate::helpers::OracleQuerier;
+    use crate::mock::{
+        instantiate_oracle_with_prices, mock_oracle_contract, MockExecuteMsg, MockInstantiateMsg,
+        MockPrice,
+    };
+    use crate::msg::PriceStatus;
+    use crate::TokenPricesResponse;
+
+    #[test]
+    fn mock_oracle_serves_scripted_prices() {
+        let mut app = App::default();
+        let code_id = app.store_code(mock_oracle_contract());
+        let tester = "tester".into_addr();
+
+        let oracle = app
+            .instantiate_contract(
+                code_id,
+                tester.clone(),
+                &MockInstantiateMsg {
+                    prices: vec![MockPrice { token_id: "btc".to_string(), price: Decimal256::from_ratio(40000u128, 1u128) }],
+                },
+                &[],
+                "Mock Oracle",
+                None,
+            )
+            .unwrap();
+
+        // Any jump is applied directly, no whitelist or deviation checks
+        app.execute_contract(
+            tester.clone(),
+            oracle.clone(),
+            &MockExecuteMsg::SetPrice { token_id: "btc".to_string(), price: Decimal256::from_ratio(80000u128, 1u128) },
+            &[],
+        )
+            .unwrap();
+        app.execute_contract(
+            tester.clone(),
+            oracle.clone(),
+            &MockExecuteMsg::SetPrice { token_id: "eth".to_string(), price: Decimal256::from_ratio(2000u128, 1u128) },
+            &[],
+        )
+            .unwrap();
+
+        let res: TokenPriceResponse = app
+            .wrap()
+            .query_wasm_smart(oracle.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
+            .unwrap();
+        assert_eq!(Decimal256::from_ratio(80000u128, 1u128), res.price);
+
+        let res: AllPricesResponse = app
+            .wrap()
+            .query_wasm_smart(oracle, &QueryMsg::AllPrices { start_after: None, limit: None })
+            .unwrap();
+        assert_eq!(2, res.prices.len());
+    }
+
+    #[test]
+    fn mock_oracle_can_make_prices_stale() {
+        let mut app = App::default();
+        let code_id = app.store_code(mock_oracle_contract());
+        let tester = "tester".into_addr();
+
+        let oracle = app
+            .instantiate_contract(
+                code_id,
+                tester.clone(),
+                &MockInstantiateMsg {
+                    prices: vec![MockPrice { token_id: "btc".to_string(), price: Decimal256::from_ratio(40000u128, 1u128) }],
+                },
+                &[],
+                "Mock Oracle",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            tester,
+            oracle.clone(),
+            &MockExecuteMsg::SetStale { token_id: "btc".to_string(), age_seconds: 3600 },
+            &[],
+        )
+            .unwrap();
+
+        let res: TokenPricesResponse = app
+            .wrap()
+            .query_wasm_smart(oracle.clone(), &QueryMsg::TokenPrices {
+                token_ids: vec!["btc".to_string()],
+                max_age_seconds: Some(60),
+            })
+            .unwrap();
+        assert_eq!(PriceStatus::Stale, res.prices[0].status);
+
+        let block_time = app.block_info().time;
+        let wrapper = app.wrap();
+        let querier = OracleQuerier::new(&wrapper, oracle).with_max_age(block_time, 60);
+        assert!(querier.price("btc").is_err());
+    }
+
+    #[test]
+    fn real_oracle_can_be_preloaded_with_prices() {
+        let mut app = App::default();
+        let owner = "owner".into_addr();
+
+        let oracle = instantiate_oracle_with_prices(
+            &mut app,
+            owner,
+            &[("btc", Decimal256::from_ratio(40000u128, 1u128)), ("eth", Decimal256::from_ratio(2000u128, 1u128))],
+        )
+            .unwrap();
+
+        let res: TokenPriceResponse = app
+            .wrap()
+            .query_wasm_smart(oracle, &QueryMsg::TokenPrice { token_id: "eth".to_string() })
+            .unwrap();
+        assert_eq!(Decimal256::from_ratio(2000u128, 1u128), res.price);
+    }
+}
+
+mod sudo_tests {
+    use super::*;
+    use crate::msg::SudoMsg;
+    use cosmwasm_std::Addr;
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+        let updater = "updater".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string()],
+                    whitelisted_updaters: vec![updater.to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            updater,
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }
+            },
+            &[],
+        )
+            .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn update_btc(app: &mut App, contract_addr: &Addr, price: u128) -> anyhow::Result<()> {
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(price, 1u128) }
+            },
+            &[],
+        )
+            .map(|_| ())
+    }
+
+    #[test]
+    fn sudo_can_replace_owner_and_admin() {
+        let (mut app, contract_addr) = setup();
+        let governance_owner = "gov_owner".into_addr();
+        let governance_admin = "gov_admin".into_addr();
+
+        app.wasm_sudo(contract_addr.clone(), &SudoMsg::UpdateOwner { new_owner: governance_owner.to_string() })
+            .unwrap();
+        app.wasm_sudo(contract_addr.clone(), &SudoMsg::UpdateAdmin { new_admin: governance_admin.to_string() })
+            .unwrap();
+
+        let res: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(governance_owner.to_string(), res.owner);
+        assert_eq!(governance_admin.to_string(), res.admin);
+    }
+
+    #[test]
+    fn sudo_pause_blocks_price_updates_until_unpaused() {
+        let (mut app, contract_addr) = setup();
+
+        app.wasm_sudo(contract_addr.clone(), &SudoMsg::Pause {}).unwrap();
+
+        let res: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        assert!(res.paused);
+
+        let err = update_btc(&mut app, &contract_addr, 40100).unwrap_err();
+        assert!(err.root_cause().to_string().contains("Price updates are paused"));
+
+        app.wasm_sudo(contract_addr.clone(), &SudoMsg::Unpause {}).unwrap();
+        update_btc(&mut app, &contract_addr, 40100).unwrap();
+    }
+
+    #[test]
+    fn admin_can_pause_through_execute() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract("updater".into_addr(), contract_addr.clone(), &ExecuteMsg::Pause {}, &[])
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Unauthorized"));
+
+        app.execute_contract("owner".into_addr(), contract_addr.clone(), &ExecuteMsg::Pause {}, &[])
+            .unwrap();
+        assert!(update_btc(&mut app, &contract_addr, 40100).is_err());
+
+        app.execute_contract("owner".into_addr(), contract_addr.clone(), &ExecuteMsg::Unpause {}, &[])
+            .unwrap();
+        assert!(update_btc(&mut app, &contract_addr, 40100).is_ok());
+    }
+
+    #[test]
+    fn sudo_force_price_bypasses_deviation_and_clears_pending() {
+        let (mut app, contract_addr) = setup();
+
+        // Create a pending update, then pause
+        update_btc(&mut app, &contract_addr, 50000).unwrap();
+        app.wasm_sudo(contract_addr.clone(), &SudoMsg::Pause {}).unwrap();
+
+        app.wasm_sudo(
+            contract_addr.clone(),
+            &SudoMsg::ForcePrice { token_id: "btc".to_string(), price: Decimal256::from_ratio(20000u128, 1u128) },
+        )
+            .unwrap();
+
+        let res: TokenPriceResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::TokenPrice { token_id: "btc".to_string() })
+            .unwrap();
+        assert_eq!(Decimal256::from_ratio(20000u128, 1u128), res.price);
+
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::PendingUpdates { start_after: None, limit: None })
+            .unwrap();
+        assert!(res.updates.is_empty());
+
+        let err = app
+            .wasm_sudo(
+                contract_addr,
+                &SudoMsg::ForcePrice { token_id: "eth".to_string(), price: Decimal256::one() },
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("Token eth not supported"));
+    }
+
+    #[test]
+    fn sudo_can_remove_updater() {
+        let (mut app, contract_addr) = setup();
+
+        app.wasm_sudo(contract_addr.clone(), &SudoMsg::RemoveUpdater { updater: "updater".into_addr().to_string() })
+            .unwrap();
+
+        let err = update_btc(&mut app, &contract_addr, 40100).unwrap_err();
+        assert!(err.root_cause().to_string().contains("Unauthorized"));
+    }
+}
+
+mod updater_status_tests {
+    use super::*;
+    use crate::msg::{PriceData, UpdaterStatusResponse, UpdaterStatusesResponse};
+    use cosmwasm_std::Addr;
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string(), "eth".to_string()],
+                    whitelisted_updaters: vec!["active".into_addr().to_string(), "silent".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        let prices = HashMap::from([
+            ("eth".to_string(), TokenPriceInfo { usd: Decimal256::from_ratio(2000u128, 1u128) }),
+            ("btc".to_string(), TokenPriceInfo { usd: Decimal256::from_ratio(40000u128, 1u128) }),
+        ]);
+        app.execute_contract(
+            "active".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdatePrices { price_data: PriceData { prices } },
+            &[],
+        )
+            .unwrap();
+
+        // Deviating submission goes pending
+        app.execute_contract(
+            "active".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo { usd: Decimal256::from_ratio(50000u128, 1u128) }
+            },
+            &[],
+        )
+            .unwrap();
+
+        (app, contract_addr)
+    }
+
+    #[test]
+    fn updater_status_tracks_submissions() {
+        let (mut app, contract_addr) = setup();
+        let submitted_at = app.block_info().time.seconds();
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(600);
+        });
+
+        let res: UpdaterStatusResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::UpdaterStatus { updater: "active".into_addr().to_string() })
+            .unwrap();
+
+        assert!(res.whitelisted);
+        assert_eq!(Some(submitted_at), res.last_submission);
+        assert_eq!(Some(600), res.seconds_since_last_submission);
+        assert_eq!(3, res.submission_count);
+        assert_eq!(1, res.pending_count);
+        assert_eq!(vec!["btc".to_string(), "eth".to_string()], res.tokens_covered);
+    }
+
+    #[test]
+    fn updater_statuses_include_silent_updaters() {
+        let (app, contract_addr) = setup();
+
+        let res: UpdaterStatusesResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::UpdaterStatuses { start_after: None, limit: None })
+            .unwrap();
+
+        assert_eq!(2, res.updaters.len());
+        let silent = res
+            .updaters
+            .iter()
+            .find(|u| u.updater == "silent".into_addr().to_string())
+            .unwrap();
+        assert_eq!(None, silent.last_submission);
+        assert_eq!(None, silent.seconds_since_last_submission);
+        assert_eq!(0, silent.submission_count);
+        assert!(silent.tokens_covered.is_empty());
+    }
+
+    #[test]
+    fn removed_updater_keeps_history_but_is_not_whitelisted() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::RemoveWhitelistedUpdater { updater: "active".into_addr().to_string() },
+            &[],
+        )
+            .unwrap();
+
+        let res: UpdaterStatusResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::UpdaterStatus { updater: "active".into_addr().to_string() })
+            .unwrap();
+
+        assert!(!res.whitelisted);
+        assert_eq!(3, res.submission_count);
+    }
+}
+
+mod submitter_tests {
+    use super::*;
+    use crate::msg::{MigrateMsg, SudoMsg, Submitter};
+    use crate::state::PRICE_HISTORY;
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Map;
+
+    fn setup() -> (App, u64, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string()],
+                    whitelisted_updaters: vec!["updater".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(40000u128, 1u128),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        (app, code_id, contract_addr)
+    }
+
+    fn submit_deviating_price(app: &mut App, contract_addr: &Addr) {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(60);
+        });
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(50000u128, 1u128),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn latest_price(app: &App, contract_addr: &Addr) -> TokenPriceResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::TokenPrice {
+                    token_id: "btc".to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn history(app: &App, contract_addr: &Addr) -> PriceHistoryResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::PriceHistory {
+                    token_id: "btc".to_string(),
+                    start_time: None,
+                    end_time: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn updater_is_recorded_on_price_and_history() {
+        let (app, _, contract_addr) = setup();
+        let updater = Submitter::Updater {
+            address: "updater".into_addr().to_string(),
+        };
+
+        assert_eq!(updater, latest_price(&app, &contract_addr).submitter);
+        assert_eq!(updater, history(&app, &contract_addr).history[0].submitter);
+    }
+
+    #[test]
+    fn pending_update_records_submitter_and_approval_records_admin() {
+        let (mut app, _, contract_addr) = setup();
+        submit_deviating_price(&mut app, &contract_addr);
+
+        let pending: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr.clone(),
+                &QueryMsg::PendingUpdates {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            Submitter::Updater {
+                address: "updater".into_addr().to_string(),
+            },
+            pending.updates[0].submitter
+        );
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ApprovePrice {
+                token_id: "btc".to_string(),
+                price: Decimal256::from_ratio(50000u128, 1u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let approved = Submitter::ApprovedBy {
+            admin: "owner".into_addr().to_string(),
+        };
+        assert_eq!(approved, latest_price(&app, &contract_addr).submitter);
+        assert_eq!(approved, history(&app, &contract_addr).history[0].submitter);
+    }
+
+    #[test]
+    fn forced_price_is_recorded_as_governance() {
+        let (mut app, _, contract_addr) = setup();
+
+        app.wasm_sudo(
+            contract_addr.clone(),
+            &SudoMsg::ForcePrice {
+                token_id: "btc".to_string(),
+                price: Decimal256::from_ratio(41000u128, 1u128),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            Submitter::Governance {},
+            latest_price(&app, &contract_addr).submitter
+        );
+    }
+
+    #[test]
+    fn migrate_rejects_other_contracts_and_downgrades() {
+        let (mut app, code_id, contract_addr) = setup();
+
+        for (name, version, error) in [
+            (
+                "crates.io:other-contract",
+                "0.1.0",
+                "cannot migrate contract crates.io:other-contract onto crates.io:gg-oracle-price"
+                    .to_string(),
+            ),
+            (
+                "crates.io:gg-oracle-price",
+                "99.0.0",
+                format!(
+                    "cannot downgrade from version 99.0.0 to {}",
+                    env!("CARGO_PKG_VERSION")
+                ),
+            ),
+        ] {
+            let mut storage = app.contract_storage_mut(&contract_addr);
+            cw2::set_contract_version(storage.as_mut(), name, version).unwrap();
+            drop(storage);
+
+            let err = app
+                .migrate_contract(
+                    "owner".into_addr(),
+                    contract_addr.clone(),
+                    &MigrateMsg {},
+                    code_id,
+                )
+                .unwrap_err();
+            assert_eq!(
+                crate::ContractError::InvalidMigration(error),
+                err.downcast().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn legacy_history_reads_as_unknown_after_migration() {
+        let (mut app, code_id, contract_addr) = setup();
+
+        // History written by the first release stored bare prices, and that release
+        // recorded no contract version
+        let legacy: Map<(&str, u64), Decimal> = Map::new("price_history");
+        let mut storage = app.contract_storage_mut(&contract_addr);
+        legacy
+            .save(
+                storage.as_mut(),
+                ("btc", 1),
+                &Decimal::from_ratio(39000u128, 1u128),
+            )
+            .unwrap();
+        storage.remove(b"contract_info");
+        drop(storage);
+
+        app.migrate_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+
+        let res = history(&app, &contract_addr);
+        assert_eq!(2, res.history.len());
+        // Newest first
+        assert_eq!(
+            Submitter::Updater {
+                address: "updater".into_addr().to_string(),
+            },
+            res.history[0].submitter
+        );
+        assert_eq!(
+            Decimal256::from_ratio(39000u128, 1u128),
+            res.history[1].price
+        );
+        assert_eq!(Submitter::Unknown {}, res.history[1].submitter);
+
+        // Legacy entries are read in place rather than rewritten
+        let storage = app.contract_storage(&contract_addr);
+        legacy.load(storage.as_ref(), ("btc", 1)).unwrap();
+        let stored = PRICE_HISTORY.load(storage.as_ref(), ("btc", 1)).unwrap();
+        assert_eq!(Submitter::Unknown {}, stored.submitter);
+
+        let version = cw2::get_contract_version(storage.as_ref()).unwrap();
+        assert_eq!(env!("CARGO_PKG_VERSION"), version.version);
+    }
+}
+
+mod audit_log_tests {
+    use super::*;
+    use crate::msg::{AuditAction, AuditLogResponse, SudoMsg};
+    use cosmwasm_std::Addr;
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string()],
+                    whitelisted_updaters: vec![],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn audit_log(
+        app: &App,
+        contract_addr: &Addr,
+        action: Option<AuditAction>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> AuditLogResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::AuditLog {
+                    action,
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn privileged_actions_are_logged_with_old_and_new_values() {
+        let (mut app, contract_addr) = setup();
+        let owner = "owner".into_addr();
+
+        app.execute_contract(
+            owner.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetDeviationThreshold {
+                threshold: Decimal::percent(10),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::AddSupportedToken {
+                token_id: "eth".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let res = audit_log(&app, &contract_addr, None, None, None);
+        assert_eq!(2, res.entries.len());
+        assert_eq!(None, res.next_start_after);
+
+        let entry = &res.entries[0];
+        assert_eq!(0, entry.id);
+        assert_eq!(Some(owner.to_string()), entry.actor);
+        assert_eq!(AuditAction::SetDeviationThreshold, entry.action);
+        assert_eq!(Some(Decimal::percent(5).to_string()), entry.old_value);
+        assert_eq!(Some(Decimal::percent(10).to_string()), entry.new_value);
+        assert_eq!(app.block_info().height, entry.height);
+        assert_eq!(app.block_info().time.seconds(), entry.timestamp);
+
+        assert_eq!(AuditAction::AddSupportedToken, res.entries[1].action);
+        assert_eq!(Some("eth".to_string()), res.entries[1].token_id);
+    }
+
+    #[test]
+    fn failed_actions_are_not_logged() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "intruder".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap_err();
+
+        let res = audit_log(&app, &contract_addr, None, None, None);
+        assert!(res.entries.is_empty());
+    }
+
+    #[test]
+    fn sudo_actions_are_logged_without_actor() {
+        let (mut app, contract_addr) = setup();
+
+        app.wasm_sudo(contract_addr.clone(), &SudoMsg::Pause {})
+            .unwrap();
+
+        let res = audit_log(&app, &contract_addr, None, None, None);
+        assert_eq!(1, res.entries.len());
+        assert_eq!(None, res.entries[0].actor);
+        assert_eq!(AuditAction::Pause, res.entries[0].action);
+        assert_eq!(Some("false".to_string()), res.entries[0].old_value);
+        assert_eq!(Some("true".to_string()), res.entries[0].new_value);
+    }
+
+    #[test]
+    fn audit_log_filters_by_action_and_paginates() {
+        let (mut app, contract_addr) = setup();
+        let owner = "owner".into_addr();
+
+        for (i, token_id) in ["eth", "atom", "osmo"].iter().enumerate() {
+            app.execute_contract(
+                owner.clone(),
+                contract_addr.clone(),
+                &ExecuteMsg::AddSupportedToken {
+                    token_id: token_id.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+            app.execute_contract(
+                owner.clone(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetDeviationThreshold {
+                    threshold: Decimal::percent(6 + i as u64),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        let first = audit_log(
+            &app,
+            &contract_addr,
+            Some(AuditAction::AddSupportedToken),
+            None,
+            Some(2),
+        );
+        assert_eq!(
+            vec![Some("eth".to_string()), Some("atom".to_string())],
+            first
+                .entries
+                .iter()
+                .map(|e| e.token_id.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(2), first.next_start_after);
+
+        let second = audit_log(
+            &app,
+            &contract_addr,
+            Some(AuditAction::AddSupportedToken),
+            first.next_start_after,
+            Some(2),
+        );
+        assert_eq!(1, second.entries.len());
+        assert_eq!(4, second.entries[0].id);
+        assert_eq!(Some("osmo".to_string()), second.entries[0].token_id);
+        assert_eq!(None, second.next_start_after);
+    }
+}
+
+mod resolved_update_tests {
+    use super::*;
+    use crate::msg::{Resolution, ResolvedUpdatesResponse};
+    use crate::ContractError;
+    use cosmwasm_std::Addr;
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string()],
+                    whitelisted_updaters: vec!["updater".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        submit_price(&mut app, &contract_addr, 40000);
+
+        (app, contract_addr)
+    }
+
+    fn submit_price(app: &mut App, contract_addr: &Addr, usd: u128) {
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(usd, 1u128),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn resolved_updates(
+        app: &App,
+        contract_addr: &Addr,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> ResolvedUpdatesResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::ResolvedUpdates {
+                    token_id: "btc".to_string(),
+                    start_after,
+                    limit,
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn rejected_update_is_kept_with_reason() {
+        let (mut app, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 50000);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::RejectPrice {
+                token_id: "btc".to_string(),
+                reason: Some("Exchange outage".to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let res = resolved_updates(&app, &contract_addr, None, None);
+        assert_eq!(1, res.updates.len());
+
+        let resolved = &res.updates[0];
+        assert_eq!(Decimal256::from_ratio(50000u128, 1u128), resolved.update.new_price);
+        assert_eq!(
+            Resolution::Rejected {
+                reason: Some("Exchange outage".to_string()),
+            },
+            resolved.resolution
+        );
+        assert_eq!(Some("owner".into_addr().to_string()), resolved.resolved_by);
+        assert_eq!(app.block_info().time.seconds(), resolved.resolved_at);
+    }
+
+    #[test]
+    fn approved_update_records_written_price() {
+        let (mut app, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 50000);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ApprovePrice {
+                token_id: "btc".to_string(),
+                price: Decimal256::from_ratio(49000u128, 1u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let res = resolved_updates(&app, &contract_addr, None, None);
+        assert_eq!(
+            Resolution::Approved {
+                price: Decimal256::from_ratio(49000u128, 1u128),
+            },
+            res.updates[0].resolution
+        );
+        assert_eq!(
+            Decimal256::from_ratio(50000u128, 1u128),
+            res.updates[0].update.new_price
+        );
+    }
+
+    #[test]
+    fn overlong_rejection_reason_fails() {
+        let (mut app, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 50000);
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::RejectPrice {
+                    token_id: "btc".to_string(),
+                    reason: Some("x".repeat(257)),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::RejectionReasonTooLong { max_length: 256 },
+            err.downcast().unwrap()
+        );
+        assert!(resolved_updates(&app, &contract_addr, None, None)
+            .updates
+            .is_empty());
+    }
+
+    #[test]
+    fn resolved_updates_paginate() {
+        let (mut app, contract_addr) = setup();
+
+        for _ in 0..3 {
+            submit_price(&mut app, &contract_addr, 50000);
+            app.execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::RejectPrice {
+                    token_id: "btc".to_string(),
+                    reason: None,
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        let first = resolved_updates(&app, &contract_addr, None, Some(2));
+        assert_eq!(
+            vec![0, 1],
+            first.updates.iter().map(|u| u.id).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(1), first.next_start_after);
+
+        let second = resolved_updates(&app, &contract_addr, first.next_start_after, Some(2));
+        assert_eq!(
+            vec![2],
+            second.updates.iter().map(|u| u.id).collect::<Vec<_>>()
+        );
+        assert_eq!(None, second.next_start_after);
+    }
+}
+
+mod pending_queue_tests {
+    use super::*;
+    use crate::msg::{
+        MigrateMsg, PendingUpdate, Resolution, ResolvedUpdatesResponse, Submitter, SudoMsg,
+    };
+    use crate::ContractError;
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Map;
+
+    fn updater(i: usize) -> Addr {
+        format!("updater{}", i).into_addr()
+    }
+
+    fn setup() -> (App, u64, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string(), "eth".to_string()],
+                    whitelisted_updaters: (0..12).map(|i| updater(i).to_string()).collect(),
+                },
+                &[],
+                "Oracle Contract",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        submit_price(&mut app, &contract_addr, 0, "btc", 40000);
+
+        (app, code_id, contract_addr)
+    }
+
+    fn submit_price(app: &mut App, contract_addr: &Addr, i: usize, token_id: &str, usd: u128) {
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(10);
+        });
+        app.execute_contract(
+            updater(i),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: token_id.to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(usd, 1u128),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn pending_updates(app: &App, contract_addr: &Addr) -> Vec<PendingUpdate> {
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::TokenPendingUpdates {
+                    token_id: "btc".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        res.updates
+    }
+
+    fn pending_ids(app: &App, contract_addr: &Addr) -> Vec<u64> {
+        pending_updates(app, contract_addr)
+            .iter()
+            .map(|u| u.id)
+            .collect()
+    }
+
+    fn resolutions(app: &App, contract_addr: &Addr) -> Vec<(u64, Resolution)> {
+        let res: ResolvedUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::ResolvedUpdates {
+                    token_id: "btc".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        res.updates
+            .into_iter()
+            .map(|u| (u.update.id, u.resolution))
+            .collect()
+    }
+
+    fn latest_price(app: &App, contract_addr: &Addr) -> Decimal256 {
+        let res: TokenPriceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::TokenPrice {
+                    token_id: "btc".to_string(),
+                },
+            )
+            .unwrap();
+        res.price
+    }
+
+    #[test]
+    fn deviating_submissions_from_different_updaters_are_queued() {
+        let (mut app, _, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+        submit_price(&mut app, &contract_addr, 2, "btc", 51000);
+
+        let updates = pending_updates(&app, &contract_addr);
+        assert_eq!(vec![0, 1], pending_ids(&app, &contract_addr));
+        assert!(updates[0].requested_at < updates[1].requested_at);
+        assert_eq!(
+            Decimal256::from_ratio(40000u128, 1u128),
+            updates[0].current_price
+        );
+        assert_eq!(
+            Decimal256::from_ratio(40000u128, 1u128),
+            updates[1].current_price
+        );
+        assert_eq!(
+            Submitter::Updater {
+                address: updater(2).to_string(),
+            },
+            updates[1].submitter
+        );
+    }
+
+    #[test]
+    fn resubmission_supersedes_same_updaters_earlier_update() {
+        let (mut app, _, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+        submit_price(&mut app, &contract_addr, 2, "btc", 51000);
+        submit_price(&mut app, &contract_addr, 1, "btc", 52000);
+
+        assert_eq!(vec![1, 2], pending_ids(&app, &contract_addr));
+        assert_eq!(
+            vec![(0, Resolution::Superseded { by: Some(2) })],
+            resolutions(&app, &contract_addr)
+        );
+    }
+
+    #[test]
+    fn approving_by_id_supersedes_older_updates_only() {
+        let (mut app, _, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+        submit_price(&mut app, &contract_addr, 2, "btc", 51000);
+        submit_price(&mut app, &contract_addr, 3, "btc", 52000);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ApprovePendingUpdate { id: 1, price: None },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            Decimal256::from_ratio(51000u128, 1u128),
+            latest_price(&app, &contract_addr)
+        );
+        assert_eq!(vec![2], pending_ids(&app, &contract_addr));
+        assert_eq!(
+            vec![
+                (
+                    1,
+                    Resolution::Approved {
+                        price: Decimal256::from_ratio(51000u128, 1u128),
+                    }
+                ),
+                (0, Resolution::Superseded { by: Some(1) }),
+            ],
+            resolutions(&app, &contract_addr)
+        );
+    }
+
+    #[test]
+    fn approve_by_token_uses_newest_update() {
+        let (mut app, _, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+        submit_price(&mut app, &contract_addr, 2, "btc", 51000);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ApprovePrice {
+                token_id: "btc".to_string(),
+                price: Decimal256::from_ratio(51000u128, 1u128),
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert!(pending_ids(&app, &contract_addr).is_empty());
+        assert_eq!(
+            vec![
+                (
+                    1,
+                    Resolution::Approved {
+                        price: Decimal256::from_ratio(51000u128, 1u128),
+                    }
+                ),
+                (0, Resolution::Superseded { by: Some(1) }),
+            ],
+            resolutions(&app, &contract_addr)
+        );
+    }
+
+    #[test]
+    fn rejecting_by_id_keeps_other_updates() {
+        let (mut app, _, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+        submit_price(&mut app, &contract_addr, 2, "btc", 51000);
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::RejectPendingUpdate {
+                id: 0,
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(vec![1], pending_ids(&app, &contract_addr));
+        assert_eq!(
+            Decimal256::from_ratio(40000u128, 1u128),
+            latest_price(&app, &contract_addr)
+        );
+    }
+
+    #[test]
+    fn unknown_pending_update_id_fails() {
+        let (mut app, _, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::ApprovePendingUpdate { id: 7, price: None },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::PendingUpdateNotFound(7),
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn full_queue_supersedes_oldest_update() {
+        let (mut app, _, contract_addr) = setup();
+        for i in 1..=11 {
+            submit_price(&mut app, &contract_addr, i, "btc", 50000 + i as u128);
+        }
+
+        let ids = pending_ids(&app, &contract_addr);
+        assert_eq!(10, ids.len());
+        assert_eq!(1, ids[0]);
+        assert_eq!(
+            vec![(0, Resolution::Superseded { by: Some(10) })],
+            resolutions(&app, &contract_addr)
+        );
+    }
+
+    #[test]
+    fn forced_price_supersedes_all_updates() {
+        let (mut app, _, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+        submit_price(&mut app, &contract_addr, 2, "btc", 51000);
+
+        app.wasm_sudo(
+            contract_addr.clone(),
+            &SudoMsg::ForcePrice {
+                token_id: "btc".to_string(),
+                price: Decimal256::from_ratio(45000u128, 1u128),
+            },
+        )
+        .unwrap();
+
+        assert!(pending_ids(&app, &contract_addr).is_empty());
+        assert_eq!(
+            vec![
+                (0, Resolution::Superseded { by: None }),
+                (1, Resolution::Superseded { by: None }),
+            ],
+            resolutions(&app, &contract_addr)
+        );
+    }
+
+    #[test]
+    fn pending_updates_are_ordered_across_tokens() {
+        let (mut app, _, contract_addr) = setup();
+        submit_price(&mut app, &contract_addr, 0, "eth", 2000);
+        submit_price(&mut app, &contract_addr, 1, "eth", 2500);
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::PendingUpdates {
+                    start_after: None,
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+
+        assert_eq!("eth", res.updates[0].token_id);
+        assert_eq!(Some(0), res.next_start_after);
+    }
+
+    #[test]
+    fn migrate_queues_legacy_pending_updates() {
+        let (mut app, code_id, contract_addr) = setup();
+
+        // The previous version kept one pending update per token
+        let legacy: Map<&str, PendingUpdate> = Map::new("pending_updates");
+        let mut storage = app.contract_storage_mut(&contract_addr);
+        for (token_id, requested_at) in [("btc", 200), ("eth", 100)] {
+            legacy
+                .save(
+                    storage.as_mut(),
+                    token_id,
+                    &PendingUpdate {
+                        id: 0,
+                        token_id: token_id.to_string(),
+                        current_price: Decimal256::one(),
+                        new_price: Decimal256::percent(200),
+                        percent_change: Decimal256::one(),
+                        requested_at,
+                        submitter: Submitter::Unknown {},
+                    },
+                )
+                .unwrap();
+        }
+        drop(storage);
+
+        app.migrate_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr.clone(),
+                &QueryMsg::PendingUpdates {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            vec![(0, "eth".to_string()), (1, "btc".to_string())],
+            res.updates
+                .into_iter()
+                .map(|u| (u.id, u.token_id))
+                .collect::<Vec<_>>()
+        );
+
+        // New submissions continue after the migrated IDs
+        submit_price(&mut app, &contract_addr, 1, "btc", 50000);
+        assert_eq!(vec![1, 2], pending_ids(&app, &contract_addr));
+    }
+}
+
+mod simulate_update_tests {
+    use super::*;
+    use crate::msg::{PriceData, SimulateUpdateResponse, SimulatedOutcome, SimulatedPrice};
+    use cosmwasm_std::Addr;
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string(), "eth".to_string()],
+                    whitelisted_updaters: vec!["updater".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(40000u128, 1u128),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn price_data(prices: &[(&str, u128)]) -> PriceData {
+        PriceData {
+            prices: prices
+                .iter()
+                .map(|(token_id, usd)| {
+                    (
+                        token_id.to_string(),
+                        TokenPriceInfo {
+                            usd: Decimal256::from_ratio(*usd, 1u128),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn simulate(
+        app: &App,
+        contract_addr: &Addr,
+        sender: &str,
+        prices: &[(&str, u128)],
+    ) -> SimulateUpdateResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::SimulateUpdate {
+                    sender: sender.into_addr().to_string(),
+                    price_data: price_data(prices),
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn simulation_predicts_updates_and_pending_without_writing() {
+        let (app, contract_addr) = setup();
+
+        let res = simulate(
+            &app,
+            &contract_addr,
+            "updater",
+            &[("eth", 2000), ("btc", 44000)],
+        );
+
+        assert!(res.applies);
+        assert_eq!(
+            vec![
+                SimulatedPrice {
+                    token_id: "btc".to_string(),
+                    outcome: SimulatedOutcome::Pending,
+                    deviation: Some(Decimal256::percent(10)),
+                },
+                SimulatedPrice {
+                    token_id: "eth".to_string(),
+                    outcome: SimulatedOutcome::Updated,
+                    deviation: None,
+                },
+            ],
+            res.results
+        );
+
+        // Nothing was written
+        let pending: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::PendingUpdates {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(pending.updates.is_empty());
+    }
+
+    #[test]
+    fn simulation_reports_small_deviation() {
+        let (app, contract_addr) = setup();
+
+        let res = simulate(&app, &contract_addr, "updater", &[("btc", 40400)]);
+
+        assert!(res.applies);
+        assert_eq!(SimulatedOutcome::Updated, res.results[0].outcome);
+        assert_eq!(Some(Decimal256::percent(1)), res.results[0].deviation);
+    }
+
+    #[test]
+    fn unsupported_token_fails_whole_batch() {
+        let (app, contract_addr) = setup();
+
+        let res = simulate(
+            &app,
+            &contract_addr,
+            "updater",
+            &[("btc", 40400), ("doge", 1)],
+        );
+
+        assert!(!res.applies);
+        assert_eq!(SimulatedOutcome::Updated, res.results[0].outcome);
+        assert_eq!(
+            SimulatedOutcome::Failed {
+                error: "Token doge not supported".to_string(),
+            },
+            res.results[1].outcome
+        );
+    }
+
+    #[test]
+    fn unauthorized_sender_fails_every_token() {
+        let (app, contract_addr) = setup();
+
+        let res = simulate(&app, &contract_addr, "intruder", &[("btc", 40400)]);
+
+        assert!(!res.applies);
+        assert_eq!(
+            SimulatedOutcome::Failed {
+                error: "Unauthorized".to_string(),
+            },
+            res.results[0].outcome
+        );
+    }
+
+    #[test]
+    fn paused_contract_fails_simulation() {
+        let (mut app, contract_addr) = setup();
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap();
+
+        let res = simulate(&app, &contract_addr, "updater", &[]);
+
+        assert!(!res.applies);
+        assert!(res.results.is_empty());
+    }
+}
+
+mod timelock_tests {
+    use super::*;
+    use crate::msg::{AuditAction, AuditLogResponse, QueuedChangesResponse, TokenConfigResponse};
+    use crate::ContractError;
+    use cosmwasm_std::Addr;
+
+    const DELAY: u64 = 86400;
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: Some("admin".into_addr().to_string()),
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string()],
+                    whitelisted_updaters: vec![],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        for msg in [
+            ExecuteMsg::SetGuardian {
+                guardian: Some("guardian".into_addr().to_string()),
+            },
+            ExecuteMsg::SetTimelockDelay {
+                delay_seconds: DELAY,
+            },
+        ] {
+            app.execute_contract(owner.clone(), contract_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        // Queue a threshold change as admin
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetDeviationThreshold {
+                threshold: Decimal::percent(10),
+            },
+            &[],
+        )
+        .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn threshold(app: &App, contract_addr: &Addr) -> Decimal {
+        let res: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::Config {})
+            .unwrap();
+        res.price_deviation_threshold
+    }
+
+    fn queued_changes(app: &App, contract_addr: &Addr) -> QueuedChangesResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::QueuedChanges {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn timelocked_change_is_queued_not_applied() {
+        let (app, contract_addr) = setup();
+
+        assert_eq!(Decimal::percent(5), threshold(&app, &contract_addr));
+
+        let res = queued_changes(&app, &contract_addr);
+        assert_eq!(1, res.changes.len());
+        let queued = &res.changes[0];
+        assert_eq!(
+            ExecuteMsg::SetDeviationThreshold {
+                threshold: Decimal::percent(10),
+            },
+            queued.change
+        );
+        assert_eq!("admin".into_addr().to_string(), queued.proposer);
+        assert_eq!(queued.queued_at + DELAY, queued.execute_after);
+    }
+
+    #[test]
+    fn queued_change_applies_after_delay() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "anyone".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::ExecuteQueued { id: 0 },
+                &[],
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::TimelockNotExpired { id: 0, .. }
+        ));
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(DELAY);
+        });
+        app.execute_contract(
+            "anyone".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ExecuteQueued { id: 0 },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(Decimal::percent(10), threshold(&app, &contract_addr));
+        assert!(queued_changes(&app, &contract_addr).changes.is_empty());
+
+        // The applied change is attributed to its proposer
+        let log: AuditLogResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::AuditLog {
+                    action: Some(AuditAction::SetDeviationThreshold),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(Some("admin".into_addr().to_string()), log.entries[0].actor);
+    }
+
+    #[test]
+    fn guardian_can_cancel_queued_change() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::CancelQueued { id: 0 },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+        app.execute_contract(
+            "guardian".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::CancelQueued { id: 0 },
+            &[],
+        )
+        .unwrap();
+        assert!(queued_changes(&app, &contract_addr).changes.is_empty());
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(DELAY);
+        });
+        let err = app
+            .execute_contract(
+                "anyone".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::ExecuteQueued { id: 0 },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::QueuedChangeNotFound(0),
+            err.downcast().unwrap()
+        );
+        assert_eq!(Decimal::percent(5), threshold(&app, &contract_addr));
+    }
+
+    #[test]
+    fn non_admin_cannot_queue_change() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "intruder".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::AddSupportedToken {
+                    token_id: "eth".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+        assert_eq!(1, queued_changes(&app, &contract_addr).changes.len());
+    }
+
+    #[test]
+    fn change_fails_if_proposer_lost_role() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateAdmin {
+                new_admin: "new_admin".into_addr().to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(DELAY);
+        });
+
+        let err = app
+            .execute_contract(
+                "anyone".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::ExecuteQueued { id: 0 },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+        assert_eq!(Decimal::percent(5), threshold(&app, &contract_addr));
+    }
+
+    #[test]
+    fn emergency_actions_are_not_timelocked() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap();
+
+        let res: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::Config {})
+            .unwrap();
+        assert!(res.paused);
+        assert_eq!(DELAY, res.timelock_delay);
+        assert_eq!(Some("guardian".into_addr().to_string()), res.guardian);
+    }
+
+    #[test]
+    fn timelock_delay_is_bounded() {
+        let (mut app, contract_addr) = setup();
+
+        // Changing the delay is itself timelocked, so bounds are checked when queued
+        let err = app
+            .execute_contract(
+                "owner".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetTimelockDelay {
+                    delay_seconds: 31 * DELAY,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::TimelockDelayTooLong {
+                max_seconds: 30 * DELAY,
+            },
+            err.downcast().unwrap()
+        );
+        assert_eq!(1, queued_changes(&app, &contract_addr).changes.len());
+    }
+
+    #[test]
+    fn invalid_changes_are_rejected_when_queued() {
+        let (mut app, contract_addr) = setup();
+
+        for (msg, error) in [
+            (
+                ExecuteMsg::SetDeviationThreshold {
+                    threshold: Decimal::zero(),
+                },
+                ContractError::InvalidThreshold(
+                    "Threshold must be between 0 and 100 percent".to_string(),
+                ),
+            ),
+            (
+                ExecuteMsg::RemoveSupportedToken {
+                    token_id: "eth".to_string(),
+                },
+                ContractError::TokenNotSupported("eth".to_string()),
+            ),
+            (
+                ExecuteMsg::AddSupportedToken {
+                    token_id: "btc".to_string(),
+                },
+                ContractError::TokenAlreadySupported("btc".to_string()),
+            ),
+            (
+                ExecuteMsg::SetTokenDecimals {
+                    token_id: "btc".to_string(),
+                    decimals: Some(37),
+                },
+                ContractError::DecimalsTooLarge { max: 36 },
+            ),
+        ] {
+            let err = app
+                .execute_contract("admin".into_addr(), contract_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(error, err.downcast().unwrap());
+        }
+
+        // Addresses are validated too
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::AddWhitelistedUpdater {
+                updater: "Not An Address".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        assert_eq!(1, queued_changes(&app, &contract_addr).changes.len());
+    }
+
+    #[test]
+    fn decimals_changes_are_timelocked() {
+        let (mut app, contract_addr) = setup();
+
+        // Decimals rescale LP share prices, so they wait out the delay like other risk parameters
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetTokenDecimals {
+                token_id: "btc".to_string(),
+                decimals: Some(8),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let res: TokenConfigResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::TokenConfig {
+                    token_id: "btc".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(None, res.decimals);
+        assert_eq!(2, queued_changes(&app, &contract_addr).changes.len());
+    }
+
+    #[test]
+    fn queued_changes_are_checked_again_on_execution() {
+        let (mut app, contract_addr) = setup();
+
+        // Both pass when queued, but the first one applied makes the second invalid
+        for _ in 0..2 {
+            app.execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::AddSupportedToken {
+                    token_id: "eth".to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(DELAY);
+        });
+
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ExecuteQueued { id: 1 },
+            &[],
+        )
+        .unwrap();
+        let err = app
+            .execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::ExecuteQueued { id: 2 },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::TokenAlreadySupported("eth".to_string()),
+            err.downcast().unwrap()
+        );
+    }
+}
+
+mod bonding_tests {
+    use super::*;
+    use crate::msg::{BondResponse, BondingConfig, PriceData};
+    use crate::ContractError;
+    use cosmwasm_std::{coins, Addr, Uint128};
+    use cw_multi_test::AppBuilder;
+
+    const DENOM: &str = "ustake";
+
+    fn bonding_config() -> BondingConfig {
+        BondingConfig {
+            denom: DENOM.to_string(),
+            min_bond: Uint128::new(1000),
+            unbonding_period: 86400,
+            rejection_slash: Decimal::percent(10),
+            treasury: "treasury".into_addr().to_string(),
+        }
+    }
+
+    fn setup() -> (App, Addr) {
+        let updater = "updater".into_addr();
+        let mut app = AppBuilder::new().build(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &updater, coins(5000, DENOM))
+                .unwrap();
+        });
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let admin = "admin".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                admin.clone(),
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string()],
+                    whitelisted_updaters: vec![updater.to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            admin,
+            contract_addr.clone(),
+            &ExecuteMsg::SetBondingConfig {
+                config: Some(bonding_config()),
+            },
+            &[],
+        )
+        .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn update_price(app: &mut App, contract_addr: &Addr, usd: u64) -> anyhow::Result<()> {
+        let mut prices = HashMap::new();
+        prices.insert(
+            "btc".to_string(),
+            TokenPriceInfo {
+                usd: Decimal256::from_ratio(usd, 1u128),
+            },
+        );
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdatePrices {
+                price_data: PriceData { prices },
+            },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn bond(app: &App, contract_addr: &Addr) -> BondResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::Bond {
+                    updater: "updater".into_addr().to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn balance(app: &App, name: &str) -> u128 {
+        app.wrap()
+            .query_balance(name.into_addr(), DENOM)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    #[test]
+    fn unbonded_updater_cannot_submit() {
+        let (mut app, contract_addr) = setup();
+
+        let err = update_price(&mut app, &contract_addr, 100).unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientBond {
+                required: Uint128::new(1000),
+                bonded: Uint128::zero(),
+            },
+            err.downcast().unwrap()
+        );
+        assert!(!bond(&app, &contract_addr).active);
+
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(1000, DENOM),
+        )
+        .unwrap();
+
+        let res = bond(&app, &contract_addr);
+        assert_eq!(Uint128::new(1000), res.bonded);
+        assert!(res.active);
+        update_price(&mut app, &contract_addr, 100).unwrap();
+    }
+
+    #[test]
+    fn bond_requires_the_bond_denom() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "updater".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::Bond {},
+                &[],
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::InvalidFunds(_)
+        ));
+    }
+
+    #[test]
+    fn unbond_and_claim_after_period() {
+        let (mut app, contract_addr) = setup();
+        let updater = "updater".into_addr();
+
+        app.execute_contract(
+            updater.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(1500, DENOM),
+        )
+        .unwrap();
+        app.execute_contract(
+            updater.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Unbond {
+                amount: Uint128::new(1000),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Remaining bond is below the minimum
+        let res = bond(&app, &contract_addr);
+        assert_eq!(Uint128::new(500), res.bonded);
+        assert_eq!(Uint128::new(1000), res.unbonding);
+        assert!(!res.active);
+
+        let err = app
+            .execute_contract(
+                updater.clone(),
+                contract_addr.clone(),
+                &ExecuteMsg::ClaimUnbonded {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::UnbondingNotComplete {
+                release_at: res.release_at.unwrap(),
+            },
+            err.downcast().unwrap()
+        );
+
+        app.update_block(|block| block.time = block.time.plus_seconds(86400));
+        app.execute_contract(
+            updater,
+            contract_addr.clone(),
+            &ExecuteMsg::ClaimUnbonded {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(4500, balance(&app, "updater"));
+        assert_eq!(Uint128::zero(), bond(&app, &contract_addr).unbonding);
+    }
+
+    #[test]
+    fn admin_slash_goes_to_treasury() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(1000, DENOM),
+        )
+        .unwrap();
+
+        let slash = ExecuteMsg::Slash {
+            updater: "updater".into_addr().to_string(),
+            amount: Uint128::new(300),
+        };
+        let err = app
+            .execute_contract("updater".into_addr(), contract_addr.clone(), &slash, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+        app.execute_contract("admin".into_addr(), contract_addr.clone(), &slash, &[])
+            .unwrap();
+
+        assert_eq!(300, balance(&app, "treasury"));
+        assert_eq!(Uint128::new(700), bond(&app, &contract_addr).bonded);
+    }
+
+    #[test]
+    fn rejected_submission_slashes_updater() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(2000, DENOM),
+        )
+        .unwrap();
+        update_price(&mut app, &contract_addr, 100).unwrap();
+
+        // A 50% jump goes to pending updates
+        update_price(&mut app, &contract_addr, 150).unwrap();
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::RejectPrice {
+                token_id: "btc".to_string(),
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(200, balance(&app, "treasury"));
+        assert_eq!(Uint128::new(1800), bond(&app, &contract_addr).bonded);
+    }
+
+    #[test]
+    fn unbonded_funds_stay_locked_while_submissions_are_pending() {
+        let (mut app, contract_addr) = setup();
+        let updater = "updater".into_addr();
+
+        app.execute_contract(
+            updater.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(2000, DENOM),
+        )
+        .unwrap();
+        update_price(&mut app, &contract_addr, 100).unwrap();
+
+        // A 50% jump goes to pending updates, then the updater unbonds everything
+        update_price(&mut app, &contract_addr, 150).unwrap();
+        app.execute_contract(
+            updater.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Unbond {
+                amount: Uint128::new(2000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_seconds(86400));
+
+        let err = app
+            .execute_contract(
+                updater.clone(),
+                contract_addr.clone(),
+                &ExecuteMsg::ClaimUnbonded {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::PendingUpdatesUnresolved {},
+            err.downcast().unwrap()
+        );
+
+        // The rejection slashes the unbonding funds, then the rest can be claimed
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::RejectPrice {
+                token_id: "btc".to_string(),
+                reason: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            updater,
+            contract_addr.clone(),
+            &ExecuteMsg::ClaimUnbonded {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(200, balance(&app, "treasury"));
+        assert_eq!(4800, balance(&app, "updater"));
+    }
+
+    #[test]
+    fn denom_cannot_change_while_bonded() {
+        let (mut app, contract_addr) = setup();
+
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(1000, DENOM),
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetBondingConfig {
+                    config: Some(BondingConfig {
+                        denom: "uother".to_string(),
+                        ..bonding_config()
+                    }),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::BondDenomLocked {}, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn bonding_can_be_disabled_once_funds_are_claimed() {
+        let (mut app, contract_addr) = setup();
+        let updater = "updater".into_addr();
+
+        app.execute_contract(
+            updater.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Bond {},
+            &coins(1000, DENOM),
+        )
+        .unwrap();
+
+        let disable = ExecuteMsg::SetBondingConfig { config: None };
+        let err = app
+            .execute_contract("admin".into_addr(), contract_addr.clone(), &disable, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::BondsOutstanding {}, err.downcast().unwrap());
+
+        app.execute_contract(
+            updater.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Unbond {
+                amount: Uint128::new(1000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.update_block(|block| block.time = block.time.plus_seconds(86400));
+        app.execute_contract(
+            updater.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::ClaimUnbonded {},
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract("admin".into_addr(), contract_addr.clone(), &disable, &[])
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                updater,
+                contract_addr.clone(),
+                &ExecuteMsg::Bond {},
+                &coins(1000, DENOM),
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::BondingDisabled {}, err.downcast().unwrap());
+    }
+}
+
+mod reward_tests {
+    use super::*;
+    use crate::msg::{
+        DerivedFeed, DerivedInput, PendingRewardsResponse, PriceData, RewardConfig,
+        RewardPoolResponse,
+    };
+    use crate::ContractError;
+    use cosmwasm_std::{coins, Addr, Uint128};
+    use cw_multi_test::AppBuilder;
+
+    const DENOM: &str = "ureward";
+
+    fn setup() -> (App, Addr) {
+        let funder = "funder".into_addr();
+        let mut app = AppBuilder::new().build(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &funder, coins(10000, DENOM))
+                .unwrap();
+        });
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let admin = "admin".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                admin.clone(),
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["btc".to_string(), "eth".to_string()],
+                    whitelisted_updaters: vec!["updater".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            admin,
+            contract_addr.clone(),
+            &ExecuteMsg::SetRewardConfig {
+                config: RewardConfig {
+                    denom: DENOM.to_string(),
+                    per_submission: Uint128::new(100),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn fund(app: &mut App, contract_addr: &Addr, amount: u128) {
+        app.execute_contract(
+            "funder".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::FundRewards {},
+            &coins(amount, DENOM),
+        )
+        .unwrap();
+    }
+
+    fn update_prices(app: &mut App, contract_addr: &Addr, tokens: &[&str]) {
+        let prices = tokens
+            .iter()
+            .map(|token| {
+                (
+                    token.to_string(),
+                    TokenPriceInfo {
+                        usd: Decimal256::percent(10000),
+                    },
+                )
+            })
+            .collect();
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdatePrices {
+                price_data: PriceData { prices },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn pending_rewards(app: &App, contract_addr: &Addr) -> Uint128 {
+        let res: PendingRewardsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::PendingRewards {
+                    updater: "updater".into_addr().to_string(),
+                },
+            )
+            .unwrap();
+        res.amount
+    }
+
+    fn reward_pool(app: &App, contract_addr: &Addr) -> RewardPoolResponse {
+        app.wrap()
+            .query_wasm_smart(contract_addr, &QueryMsg::RewardPool {})
+            .unwrap()
+    }
+
+    #[test]
+    fn accepted_prices_accrue_rewards() {
+        let (mut app, contract_addr) = setup();
+        fund(&mut app, &contract_addr, 1000);
+
+        update_prices(&mut app, &contract_addr, &["btc", "eth"]);
+
+        assert_eq!(Uint128::new(200), pending_rewards(&app, &contract_addr));
+        let pool = reward_pool(&app, &contract_addr);
+        assert_eq!(Uint128::new(800), pool.available);
+        assert_eq!(Uint128::new(200), pool.accrued);
+    }
+
+    #[test]
+    fn pending_updates_do_not_accrue() {
+        let (mut app, contract_addr) = setup();
+        fund(&mut app, &contract_addr, 1000);
+        update_prices(&mut app, &contract_addr, &["btc"]);
+
+        // A 50% jump goes to pending updates
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::percent(15000),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(Uint128::new(100), pending_rewards(&app, &contract_addr));
+    }
+
+    #[test]
+    fn only_checked_updater_submissions_accrue() {
+        let (mut app, contract_addr) = setup();
+        fund(&mut app, &contract_addr, 1000);
+        let admin = "admin".into_addr();
+
+        // The admin's own price writes earn nothing
+        app.execute_contract(
+            admin.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "btc".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::percent(10000),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+        let res: PendingRewardsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::PendingRewards {
+                    updater: admin.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(Uint128::zero(), res.amount);
+
+        // Derived inputs are deviation-checked like submissions and earn the same
+        for msg in [
+            ExecuteMsg::AddSupportedToken {
+                token_id: "steth".to_string(),
+            },
+            ExecuteMsg::SetDerivedFeed {
+                token_id: "steth".to_string(),
+                feed: Some(DerivedFeed::StakingDerivative {
+                    underlying: "eth".to_string(),
+                }),
+            },
+        ] {
+            app.execute_contract(admin.clone(), contract_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+        update_prices(&mut app, &contract_addr, &["eth"]);
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateDerivedInput {
+                token_id: "steth".to_string(),
+                input: DerivedInput::ExchangeRate {
+                    rate: Decimal::percent(110),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(Uint128::new(200), pending_rewards(&app, &contract_addr));
+        assert_eq!(Uint128::new(200), reward_pool(&app, &contract_addr).accrued);
+    }
+
+    #[test]
+    fn resubmissions_in_the_same_block_earn_once() {
+        let (mut app, contract_addr) = setup();
+        fund(&mut app, &contract_addr, 1000);
+
+        for _ in 0..3 {
+            update_prices(&mut app, &contract_addr, &["btc"]);
+        }
+        assert_eq!(Uint128::new(100), pending_rewards(&app, &contract_addr));
+
+        app.update_block(|block| block.time = block.time.plus_seconds(5));
+        update_prices(&mut app, &contract_addr, &["btc"]);
+        assert_eq!(Uint128::new(200), pending_rewards(&app, &contract_addr));
+    }
+
+    #[test]
+    fn reward_config_is_validated() {
+        let (mut app, contract_addr) = setup();
+
+        for (config, error) in [
+            (
+                RewardConfig {
+                    denom: DENOM.to_string(),
+                    per_submission: Uint128::zero(),
+                },
+                "reward per submission must be greater than zero",
+            ),
+            (
+                RewardConfig {
+                    denom: String::new(),
+                    per_submission: Uint128::new(100),
+                },
+                "denom cannot be empty",
+            ),
+        ] {
+            let err = app
+                .execute_contract(
+                    "admin".into_addr(),
+                    contract_addr.clone(),
+                    &ExecuteMsg::SetRewardConfig { config },
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::InvalidRewardConfig(error.to_string()),
+                err.downcast().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn rewards_are_capped_by_pool() {
+        let (mut app, contract_addr) = setup();
+        fund(&mut app, &contract_addr, 150);
+
+        update_prices(&mut app, &contract_addr, &["btc", "eth"]);
+
+        assert_eq!(Uint128::new(150), pending_rewards(&app, &contract_addr));
+        assert_eq!(Uint128::zero(), reward_pool(&app, &contract_addr).available);
+    }
+
+    #[test]
+    fn claim_rewards_pays_updater() {
+        let (mut app, contract_addr) = setup();
+        fund(&mut app, &contract_addr, 1000);
+        update_prices(&mut app, &contract_addr, &["btc"]);
+
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ClaimRewards {},
+            &[],
+        )
+        .unwrap();
+
+        let balance = app
+            .wrap()
+            .query_balance("updater".into_addr(), DENOM)
+            .unwrap();
+        assert_eq!(Uint128::new(100), balance.amount);
+        assert_eq!(Uint128::zero(), pending_rewards(&app, &contract_addr));
+        assert_eq!(Uint128::zero(), reward_pool(&app, &contract_addr).accrued);
+
+        let err = app
+            .execute_contract(
+                "updater".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::ClaimRewards {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::NothingToClaim {}, err.downcast().unwrap());
+    }
+}
+
+mod composite_feed_tests {
+    use super::*;
+    use crate::msg::{CompositeFeed, FeedComponent, PriceData, Submitter, TokenConfigResponse};
+    use crate::ContractError;
+    use cosmwasm_std::Addr;
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let admin = "admin".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                admin,
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec![
+                        "btc".to_string(),
+                        "eth".to_string(),
+                        "index".to_string(),
+                        "meta".to_string(),
+                    ],
+                    whitelisted_updaters: vec!["updater".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn feed(components: &[(&str, u64)], divisor: Option<u64>) -> CompositeFeed {
+        CompositeFeed {
+            components: components
+                .iter()
+                .map(|(token_id, weight)| FeedComponent {
+                    token_id: token_id.to_string(),
+                    weight: Decimal::percent(*weight),
+                })
+                .collect(),
+            divisor: divisor.map(|d| Decimal::from_ratio(d, 1u128)),
+        }
+    }
+
+    fn set_feed(
+        app: &mut App,
+        contract_addr: &Addr,
+        token_id: &str,
+        feed: Option<CompositeFeed>,
+    ) -> anyhow::Result<()> {
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetCompositeFeed {
+                token_id: token_id.to_string(),
+                feed,
+            },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn update_prices(
+        app: &mut App,
+        contract_addr: &Addr,
+        prices: &[(&str, u64)],
+    ) -> anyhow::Result<()> {
+        let prices = prices
+            .iter()
+            .map(|(token_id, usd)| {
+                (
+                    token_id.to_string(),
+                    TokenPriceInfo {
+                        usd: Decimal256::from_ratio(*usd, 1u128),
+                    },
+                )
+            })
+            .collect();
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdatePrices {
+                price_data: PriceData { prices },
+            },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn price(app: &App, contract_addr: &Addr, token_id: &str) -> TokenPriceResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::TokenPrice {
+                    token_id: token_id.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn composite_is_repriced_when_components_change() {
+        let (mut app, contract_addr) = setup();
+        update_prices(&mut app, &contract_addr, &[("btc", 100), ("eth", 10)]).unwrap();
+
+        // (2 * 100 + 10 * 10) / 3
+        set_feed(
+            &mut app,
+            &contract_addr,
+            "index",
+            Some(feed(&[("btc", 200), ("eth", 1000)], Some(3))),
+        )
+        .unwrap();
+        let res = price(&app, &contract_addr, "index");
+        assert_eq!(Decimal256::from_ratio(100u128, 1u128), res.price);
+        assert_eq!(Submitter::Derived {}, res.submitter);
+
+        app.update_block(|block| block.time = block.time.plus_seconds(60));
+        update_prices(&mut app, &contract_addr, &[("btc", 103)]).unwrap();
+        assert_eq!(
+            Decimal256::from_ratio(102u128, 1u128),
+            price(&app, &contract_addr, "index").price
+        );
+
+        let history: PriceHistoryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::PriceHistory {
+                    token_id: "index".to_string(),
+                    start_time: None,
+                    end_time: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(2, history.history.len());
+    }
+
+    #[test]
+    fn nested_composites_are_repriced() {
+        let (mut app, contract_addr) = setup();
+        update_prices(&mut app, &contract_addr, &[("btc", 100), ("eth", 10)]).unwrap();
+        set_feed(
+            &mut app,
+            &contract_addr,
+            "index",
+            Some(feed(&[("btc", 100), ("eth", 100)], None)),
+        )
+        .unwrap();
+        set_feed(
+            &mut app,
+            &contract_addr,
+            "meta",
+            Some(feed(&[("index", 50)], None)),
+        )
+        .unwrap();
+        assert_eq!(
+            Decimal256::from_ratio(55u128, 1u128),
+            price(&app, &contract_addr, "meta").price
+        );
+
+        update_prices(&mut app, &contract_addr, &[("eth", 10), ("btc", 104)]).unwrap();
+        assert_eq!(
+            Decimal256::from_ratio(57u128, 1u128),
+            price(&app, &contract_addr, "meta").price
+        );
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let (mut app, contract_addr) = setup();
+        set_feed(
+            &mut app,
+            &contract_addr,
+            "index",
+            Some(feed(&[("btc", 100)], None)),
+        )
+        .unwrap();
+        set_feed(
+            &mut app,
+            &contract_addr,
+            "meta",
+            Some(feed(&[("index", 100)], None)),
+        )
+        .unwrap();
+
+        let err = set_feed(
+            &mut app,
+            &contract_addr,
+            "index",
+            Some(feed(&[("btc", 100), ("meta", 100)], None)),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::FeedCycle("index".to_string()),
+            err.downcast().unwrap()
+        );
+
+        let err = set_feed(
+            &mut app,
+            &contract_addr,
+            "btc",
+            Some(feed(&[("btc", 100)], None)),
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::FeedCycle("btc".to_string()),
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn composite_cannot_be_submitted() {
+        let (mut app, contract_addr) = setup();
+        set_feed(
+            &mut app,
+            &contract_addr,
+            "index",
+            Some(feed(&[("btc", 100)], None)),
+        )
+        .unwrap();
+
+        let err = update_prices(&mut app, &contract_addr, &[("index", 1)]).unwrap_err();
+        assert_eq!(
+            ContractError::DerivedFeed("index".to_string()),
+            err.downcast().unwrap()
+        );
+
+        // Clearing the feed makes the token submittable again
+        set_feed(&mut app, &contract_addr, "index", None).unwrap();
+        let res: TokenConfigResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::TokenConfig {
+                    token_id: "index".to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(None, res.composite);
+        update_prices(&mut app, &contract_addr, &[("index", 1)]).unwrap();
+    }
+
+    #[test]
+    fn component_cannot_be_removed() {
+        let (mut app, contract_addr) = setup();
+        set_feed(
+            &mut app,
+            &contract_addr,
+            "index",
+            Some(feed(&[("btc", 100)], None)),
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::RemoveSupportedToken {
+                    token_id: "btc".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::TokenInUse {
+                token_id: "btc".to_string(),
+                dependent: "index".to_string(),
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_feeds_are_rejected() {
+        let (mut app, contract_addr) = setup();
+
+        for feed in [
+            feed(&[], None),
+            feed(&[("btc", 0)], None),
+            feed(&[("btc", 100)], Some(0)),
+            feed(&[("btc", 50), ("btc", 50)], None),
+        ] {
+            let err = set_feed(&mut app, &contract_addr, "index", Some(feed)).unwrap_err();
+            assert!(matches!(
+                err.downcast().unwrap(),
+                ContractError::InvalidCompositeFeed(_)
+            ));
+        }
+    }
+}
+
+mod derived_feed_tests {
+    use super::*;
+    use crate::msg::{DerivedFeed, DerivedInput, PriceData};
+    use crate::ContractError;
+    use cosmwasm_std::{Addr, Uint128};
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let admin = "admin".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                admin.clone(),
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec![
+                        "atom".to_string(),
+                        "statom".to_string(),
+                        "usdc".to_string(),
+                        "lp".to_string(),
+                    ],
+                    whitelisted_updaters: vec!["updater".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        for (token_id, feed) in [
+            (
+                "statom",
+                DerivedFeed::StakingDerivative {
+                    underlying: "atom".to_string(),
+                },
+            ),
+            (
+                "lp",
+                DerivedFeed::LpShare {
+                    token_a: "atom".to_string(),
+                    token_b: "usdc".to_string(),
+                },
+            ),
+        ] {
+            app.execute_contract(
+                admin.clone(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetDerivedFeed {
+                    token_id: token_id.to_string(),
+                    feed: Some(feed),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        (app, contract_addr)
+    }
+
+    fn update_prices(app: &mut App, contract_addr: &Addr, prices: &[(&str, Decimal256)]) {
+        let prices = prices
+            .iter()
+            .map(|(token_id, usd)| (token_id.to_string(), TokenPriceInfo { usd: *usd }))
+            .collect();
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdatePrices {
+                price_data: PriceData { prices },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn update_input(
+        app: &mut App,
+        contract_addr: &Addr,
+        token_id: &str,
+        input: DerivedInput,
+    ) -> anyhow::Result<()> {
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateDerivedInput {
+                token_id: token_id.to_string(),
+                input,
+            },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn reserves(reserve_a: u128, reserve_b: u128, total_supply: u128) -> DerivedInput {
+        DerivedInput::Reserves {
+            reserve_a: Uint128::new(reserve_a),
+            reserve_b: Uint128::new(reserve_b),
+            total_supply: Uint128::new(total_supply),
+        }
+    }
+
+    fn price(app: &App, contract_addr: &Addr, token_id: &str) -> Decimal256 {
+        let res: TokenPriceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::TokenPrice {
+                    token_id: token_id.to_string(),
+                },
+            )
+            .unwrap();
+        res.price
+    }
+
+    #[test]
+    fn staking_derivative_follows_rate_and_underlying() {
+        let (mut app, contract_addr) = setup();
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::percent(1000))],
+        );
+
+        update_input(
+            &mut app,
+            &contract_addr,
+            "statom",
+            DerivedInput::ExchangeRate {
+                rate: Decimal::percent(120),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            Decimal256::percent(1200),
+            price(&app, &contract_addr, "statom")
+        );
+
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::percent(1040))],
+        );
+        assert_eq!(
+            Decimal256::percent(1248),
+            price(&app, &contract_addr, "statom")
+        );
+    }
+
+    #[test]
+    fn deviating_input_waits_for_approval() {
+        let (mut app, contract_addr) = setup();
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::percent(1000))],
+        );
+        let rate = |percent| DerivedInput::ExchangeRate {
+            rate: Decimal::percent(percent),
+        };
+        update_input(&mut app, &contract_addr, "statom", rate(120)).unwrap();
+
+        // A 67% jump in the derived price is queued instead of written
+        update_input(&mut app, &contract_addr, "statom", rate(200)).unwrap();
+        assert_eq!(
+            Decimal256::percent(1200),
+            price(&app, &contract_addr, "statom")
+        );
+        let pending: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::TokenPendingUpdates {
+                    token_id: "statom".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(Decimal256::percent(2000), pending.updates[0].new_price);
+
+        // Sources keep repricing from the accepted input until the update is approved
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::percent(1040))],
+        );
+        assert_eq!(
+            Decimal256::percent(1248),
+            price(&app, &contract_addr, "statom")
+        );
+
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ApprovePrice {
+                token_id: "statom".to_string(),
+                price: Decimal256::percent(2080),
+            },
+            &[],
+        )
+        .unwrap();
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::percent(1000))],
+        );
+        assert_eq!(
+            Decimal256::percent(2000),
+            price(&app, &contract_addr, "statom")
+        );
+    }
+
+    #[test]
+    fn lp_price_ignores_reserve_imbalance() {
+        let (mut app, contract_addr) = setup();
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::one()), ("usdc", Decimal256::one())],
+        );
+
+        // 2 * sqrt(1000 * 1000) / 100
+        update_input(&mut app, &contract_addr, "lp", reserves(1000, 1000, 100)).unwrap();
+        assert_eq!(Decimal256::percent(2000), price(&app, &contract_addr, "lp"));
+
+        // Same invariant after an off-balance trade, while spot reserves are worth 42.5 per share
+        update_input(&mut app, &contract_addr, "lp", reserves(4000, 250, 100)).unwrap();
+        assert_eq!(Decimal256::percent(2000), price(&app, &contract_addr, "lp"));
+
+        // Underlying prices still move the share price
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::percent(104))],
+        );
+        assert_eq!(
+            Decimal256::from_ratio(20u128, 1u128) * Decimal256::percent(104).sqrt(),
+            price(&app, &contract_addr, "lp")
+        );
+    }
+
+    #[test]
+    fn lp_reserves_are_scaled_by_token_decimals() {
+        let (mut app, contract_addr) = setup();
+        for (token_id, decimals) in [("atom", 18), ("usdc", 6), ("lp", 18)] {
+            app.execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetTokenDecimals {
+                    token_id: token_id.to_string(),
+                    decimals: Some(decimals),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[
+                ("atom", Decimal256::percent(400)),
+                ("usdc", Decimal256::one()),
+            ],
+        );
+
+        // 2 atom and 8 usdc behind one share: 2 * sqrt(2 * 8 * 4 * 1) / 1
+        update_input(
+            &mut app,
+            &contract_addr,
+            "lp",
+            reserves(
+                2_000_000_000_000_000_000,
+                8_000_000,
+                1_000_000_000_000_000_000,
+            ),
+        )
+        .unwrap();
+        assert_eq!(Decimal256::percent(1600), price(&app, &contract_addr, "lp"));
+    }
+
+    #[test]
+    fn small_lp_pools_keep_fractional_invariant() {
+        let (mut app, contract_addr) = setup();
+        update_prices(
+            &mut app,
+            &contract_addr,
+            &[("atom", Decimal256::one()), ("usdc", Decimal256::one())],
+        );
+
+        // 2 * sqrt(2 * 3) / 1, where a truncated square root would give 4
+        update_input(&mut app, &contract_addr, "lp", reserves(2, 3, 1)).unwrap();
+        assert_eq!(
+            Decimal256::from_ratio(2u128, 1u128) * Decimal256::from_ratio(6u128, 1u128).sqrt(),
+            price(&app, &contract_addr, "lp")
+        );
+    }
+
+    #[test]
+    fn mismatched_input_is_rejected() {
+        let (mut app, contract_addr) = setup();
+
+        let err = update_input(&mut app, &contract_addr, "statom", reserves(1, 1, 1)).unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::InvalidDerivedInput(_)
+        ));
+
+        let err = update_input(&mut app, &contract_addr, "lp", reserves(1, 1, 0)).unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::InvalidDerivedInput(_)
+        ));
+    }
+
+    #[test]
+    fn derived_feed_cannot_be_submitted_directly() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "updater".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::UpdateSinglePrice {
+                    token_id: "statom".to_string(),
+                    price_info: TokenPriceInfo {
+                        usd: Decimal256::one(),
+                    },
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::DerivedFeed("statom".to_string()),
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn derived_cycles_are_rejected() {
+        let (mut app, contract_addr) = setup();
+
+        let err = app
+            .execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetDerivedFeed {
+                    token_id: "atom".to_string(),
+                    feed: Some(DerivedFeed::StakingDerivative {
+                        underlying: "statom".to_string(),
+                    }),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::FeedCycle("atom".to_string()),
+            err.downcast().unwrap()
+        );
+    }
+}
+
+mod transformed_price_tests {
+    use super::*;
+    use crate::msg::{Submitter, SudoMsg, TokenPrice, TransformedPriceResponse};
+    use crate::state::PRICES;
+    use crate::ContractError;
+    use cosmwasm_std::{Addr, Decimal256, StdResult, Uint256};
+
+    fn setup(price: Decimal256) -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let admin = "admin".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                admin,
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["atom".to_string()],
+                    whitelisted_updaters: vec![],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        app.wasm_sudo(
+            contract_addr.clone(),
+            &SudoMsg::ForcePrice {
+                token_id: "atom".to_string(),
+                price,
+            },
+        )
+        .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn set_decimals(app: &mut App, contract_addr: &Addr, decimals: u8) -> anyhow::Result<()> {
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetTokenDecimals {
+                token_id: "atom".to_string(),
+                decimals: Some(decimals),
+            },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn transformed(
+        app: &App,
+        contract_addr: &Addr,
+        invert: bool,
+        per_base_unit: bool,
+    ) -> StdResult<TransformedPriceResponse> {
+        app.wrap().query_wasm_smart(
+            contract_addr,
+            &QueryMsg::TransformedPrice {
+                token_id: "atom".to_string(),
+                invert,
+                per_base_unit,
+            },
+        )
+    }
+
+    #[test]
+    fn inverts_and_scales_price() {
+        let (mut app, contract_addr) = setup(Decimal256::percent(200));
+        set_decimals(&mut app, &contract_addr, 6).unwrap();
+
+        let cases = [
+            (false, false, Decimal256::percent(200)),
+            (true, false, Decimal256::percent(50)),
+            (false, true, Decimal256::from_ratio(2u128, 1_000_000u128)),
+            (true, true, Decimal256::from_ratio(500_000u128, 1u128)),
+        ];
+        for (invert, per_base_unit, expected) in cases {
+            let res = transformed(&app, &contract_addr, invert, per_base_unit).unwrap();
+            assert_eq!(expected, res.price);
+            assert_eq!(
+                expected,
+                Decimal256::from_atomics(res.atomics, res.exponent).unwrap()
+            );
+            assert_eq!(invert, res.inverted);
+            assert_eq!(per_base_unit.then_some(6), res.decimals);
+        }
+    }
+
+    #[test]
+    fn base_unit_prices_keep_digits_past_decimal256_precision() {
+        // $0.50 over 1e18 base units is 5e-19 USD, below the smallest Decimal256
+        let (mut app, contract_addr) = setup(Decimal256::percent(50));
+        set_decimals(&mut app, &contract_addr, 18).unwrap();
+
+        let res = transformed(&app, &contract_addr, false, true).unwrap();
+        assert_eq!(Decimal256::zero(), res.price);
+        assert_eq!(Uint256::from(500_000_000_000_000_000u128), res.atomics);
+        assert_eq!(36, res.exponent);
+
+        // Inverted, the base unit count per USD is exact again: 2e18
+        let res = transformed(&app, &contract_addr, true, true).unwrap();
+        assert_eq!(Decimal256::from_ratio(2 * 10u128.pow(18), 1u128), res.price);
+        assert_eq!(18, res.exponent);
+    }
+
+    #[test]
+    fn base_unit_prices_of_18_decimal_tokens() {
+        let (mut app, contract_addr) = setup(Decimal256::from_ratio(321_745u128, 100u128));
+        set_decimals(&mut app, &contract_addr, 18).unwrap();
+
+        // $3217.45 per token is 3.21745e-15 USD per wei
+        let res = transformed(&app, &contract_addr, false, true).unwrap();
+        assert_eq!(Decimal256::raw(3217), res.price);
+        assert_eq!(
+            Uint256::from(3_217_450_000_000_000_000_000u128),
+            res.atomics
+        );
+        assert_eq!(36, res.exponent);
+    }
+
+    #[test]
+    fn large_results_use_decimal256_range() {
+        let (mut app, contract_addr) = setup(Decimal256::raw(1));
+        set_decimals(&mut app, &contract_addr, 18).unwrap();
+
+        // 1e18 base units per token over a price of 1e-18 USD
+        let res = transformed(&app, &contract_addr, true, true).unwrap();
+        assert_eq!(Decimal256::from_ratio(10u128.pow(36), 1u128), res.price);
+    }
+
+    #[test]
+    fn zero_price_cannot_be_inverted() {
+        let (mut app, contract_addr) = setup(Decimal256::one());
+
+        // Zero prices are rejected on write, but may predate the check
+        let mut storage = app.contract_storage_mut(&contract_addr);
+        PRICES
+            .save(
+                storage.as_mut(),
+                "atom",
+                &TokenPrice {
+                    usd: Decimal256::zero(),
+                    last_updated: 0,
+                    submitter: Submitter::Unknown {},
+                },
+            )
+            .unwrap();
+        drop(storage);
+
+        assert!(transformed(&app, &contract_addr, true, false).is_err());
+        assert_eq!(
+            Decimal256::zero(),
+            transformed(&app, &contract_addr, false, false)
+                .unwrap()
+                .price
+        );
+    }
+
+    #[test]
+    fn scaling_requires_decimals() {
+        let (mut app, contract_addr) = setup(Decimal256::one());
+
+        assert!(transformed(&app, &contract_addr, false, true).is_err());
+
+        let err = set_decimals(&mut app, &contract_addr, 37).unwrap_err();
+        assert_eq!(
+            ContractError::DecimalsTooLarge { max: 36 },
+            err.downcast().unwrap()
+        );
+    }
+}
+
+mod decimal256_price_tests {
+    use super::*;
+    use crate::msg::{MigrateMsg, PendingUpdatesResponse};
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Map;
+
+    fn setup() -> (App, u64, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: Some(owner.to_string()),
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["weth".to_string()],
+                    whitelisted_updaters: vec!["updater".into_addr().to_string()],
+                },
+                &[],
+                "Oracle Contract",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        (app, code_id, contract_addr)
+    }
+
+    fn update_price(app: &mut App, contract_addr: &Addr, usd: Decimal256) {
+        app.execute_contract(
+            "updater".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "weth".to_string(),
+                price_info: TokenPriceInfo { usd },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn price(app: &App, contract_addr: &Addr) -> Decimal256 {
+        let res: TokenPriceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::TokenPrice {
+                    token_id: "weth".to_string(),
+                },
+            )
+            .unwrap();
+        res.price
+    }
+
+    #[test]
+    fn prices_beyond_decimal_range_are_stored() {
+        let (mut app, _, contract_addr) = setup();
+        let large = Decimal256::from_ratio(10u128.pow(30), 1u128);
+        assert!(Decimal::try_from(large).is_err());
+
+        update_price(&mut app, &contract_addr, large);
+        assert_eq!(large, price(&app, &contract_addr));
+
+        // Small moves at this magnitude pass the deviation check
+        let moved = large * Decimal256::percent(101);
+        update_price(&mut app, &contract_addr, moved);
+        assert_eq!(moved, price(&app, &contract_addr));
+
+        // Large moves go to pending updates with the exact deviation
+        update_price(&mut app, &contract_addr, moved * Decimal256::percent(150));
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::PendingUpdates {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(Decimal256::percent(50), res.updates[0].percent_change);
+    }
+
+    #[test]
+    fn prices_stored_as_decimal_load_after_migration() {
+        let (mut app, code_id, contract_addr) = setup();
+
+        // Price layout of the previous version
+        #[cw_serde]
+        struct LegacyTokenPrice {
+            usd: Decimal,
+            last_updated: u64,
+        }
+        let legacy: Map<&str, LegacyTokenPrice> = Map::new("prices");
+        let mut storage = app.contract_storage_mut(&contract_addr);
+        legacy
+            .save(
+                storage.as_mut(),
+                "weth",
+                &LegacyTokenPrice {
+                    usd: Decimal::raw(1),
+                    last_updated: 1,
+                },
+            )
+            .unwrap();
+        drop(storage);
+
+        app.migrate_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+
+        assert_eq!(Decimal256::raw(1), price(&app, &contract_addr));
+    }
+}
+
+mod bootstrap_tests {
+    use super::*;
+    use crate::msg::{BootstrapPolicy, Submitter, SudoMsg};
+    use crate::ContractError;
+    use cosmwasm_std::{Addr, StdResult};
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let admin = "admin".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                admin,
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["atom".to_string()],
+                    whitelisted_updaters: vec![
+                        "updater1".into_addr().to_string(),
+                        "updater2".into_addr().to_string(),
+                        "updater3".into_addr().to_string(),
+                    ],
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        (app, contract_addr)
+    }
+
+    fn set_policy(
+        app: &mut App,
+        contract_addr: &Addr,
+        policy: BootstrapPolicy,
+    ) -> anyhow::Result<()> {
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetBootstrapPolicy { policy },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn submit(
+        app: &mut App,
+        contract_addr: &Addr,
+        sender: &str,
+        usd: u64,
+    ) -> anyhow::Result<cw_multi_test::AppResponse> {
+        app.execute_contract(
+            sender.into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "atom".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(usd, 1u128),
+                },
+            },
+            &[],
+        )
+    }
+
+    fn price(app: &App, contract_addr: &Addr) -> StdResult<TokenPriceResponse> {
+        app.wrap().query_wasm_smart(
+            contract_addr,
+            &QueryMsg::TokenPrice {
+                token_id: "atom".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn zero_prices_are_rejected() {
+        let (mut app, contract_addr) = setup();
+
+        let err = submit(&mut app, &contract_addr, "updater1", 0).unwrap_err();
+        assert_eq!(
+            ContractError::ZeroPrice("atom".to_string()),
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .wasm_sudo(
+                contract_addr.clone(),
+                &SudoMsg::ForcePrice {
+                    token_id: "atom".to_string(),
+                    price: Decimal256::zero(),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ZeroPrice("atom".to_string()),
+            err.downcast().unwrap()
+        );
+
+        // An approval cannot override a pending price with zero either
+        set_policy(&mut app, &contract_addr, BootstrapPolicy::AdminOnly {}).unwrap();
+        submit(&mut app, &contract_addr, "updater1", 10).unwrap();
+        let err = app
+            .execute_contract(
+                "admin".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::ApprovePrice {
+                    token_id: "atom".to_string(),
+                    price: Decimal256::zero(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ZeroPrice("atom".to_string()),
+            err.downcast().unwrap()
+        );
+        assert!(price(&app, &contract_addr).is_err());
+    }
+
+    #[test]
+    fn trusted_policy_accepts_first_submission() {
+        let (mut app, contract_addr) = setup();
+
+        let res: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&contract_addr, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(BootstrapPolicy::Trusted {}, res.bootstrap_policy);
+
+        submit(&mut app, &contract_addr, "updater1", 10).unwrap();
+        assert_eq!(
+            Decimal256::from_ratio(10u128, 1u128),
+            price(&app, &contract_addr).unwrap().price
+        );
+    }
+
+    #[test]
+    fn admin_only_policy_queues_first_updater_price() {
+        let (mut app, contract_addr) = setup();
+        set_policy(&mut app, &contract_addr, BootstrapPolicy::AdminOnly {}).unwrap();
+
+        submit(&mut app, &contract_addr, "updater1", 10).unwrap();
+        assert!(price(&app, &contract_addr).is_err());
+
+        let res: PendingUpdatesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::PendingUpdates {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(1, res.updates.len());
+        assert_eq!(Decimal256::zero(), res.updates[0].current_price);
+
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::ApprovePendingUpdate {
+                id: res.updates[0].id,
+                price: None,
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            Decimal256::from_ratio(10u128, 1u128),
+            price(&app, &contract_addr).unwrap().price
+        );
+
+        // Later prices follow the usual deviation checks
+        submit(&mut app, &contract_addr, "updater1", 10).unwrap();
+        assert_eq!(
+            Submitter::Updater {
+                address: "updater1".into_addr().to_string()
+            },
+            price(&app, &contract_addr).unwrap().submitter
+        );
+    }
+
+    #[test]
+    fn quorum_policy_writes_median_once_reached() {
+        let (mut app, contract_addr) = setup();
+        set_policy(
+            &mut app,
+            &contract_addr,
+            BootstrapPolicy::Quorum {
+                submitters: 3,
+                window_seconds: 3600,
+            },
+        )
+        .unwrap();
+
+        let res = submit(&mut app, &contract_addr, "updater1", 10).unwrap();
+        assert!(
+            res.has_event(&cosmwasm_std::Event::new("wasm").add_attribute("status", "collecting"))
+        );
+        // Resubmitting replaces the updater's earlier price
+        submit(&mut app, &contract_addr, "updater1", 11).unwrap();
+        submit(&mut app, &contract_addr, "updater2", 50).unwrap();
+        assert!(price(&app, &contract_addr).is_err());
+
+        submit(&mut app, &contract_addr, "updater3", 12).unwrap();
+        let res = price(&app, &contract_addr).unwrap();
+        assert_eq!(Decimal256::from_ratio(12u128, 1u128), res.price);
+
+        let mut updaters = vec![
+            "updater1".into_addr().to_string(),
+            "updater2".into_addr().to_string(),
+            "updater3".into_addr().to_string(),
+        ];
+        updaters.sort();
+        assert_eq!(
+            Submitter::Quorum {
+                updaters,
+                outliers: vec![]
+            },
+            res.submitter
+        );
+    }
+
+    #[test]
+    fn quorum_drops_submissions_outside_the_window() {
+        let (mut app, contract_addr) = setup();
+        set_policy(
+            &mut app,
+            &contract_addr,
+            BootstrapPolicy::Quorum {
+                submitters: 2,
+                window_seconds: 60,
+            },
+        )
+        .unwrap();
+
+        submit(&mut app, &contract_addr, "updater1", 10).unwrap();
+        app.update_block(|block| block.time = block.time.plus_seconds(61));
+
+        // The first submission has expired, so the round is still one short
+        submit(&mut app, &contract_addr, "updater2", 12).unwrap();
+        assert!(price(&app, &contract_addr).is_err());
+
+        submit(&mut app, &contract_addr, "updater3", 14).unwrap();
+        let res = price(&app, &contract_addr).unwrap();
+        assert_eq!(Decimal256::from_ratio(13u128, 1u128), res.price);
+        let Submitter::Quorum { updaters, .. } = res.submitter else {
+            panic!("expected a quorum price");
+        };
+        assert!(!updaters.contains(&"updater1".into_addr().to_string()));
+    }
+
+    #[test]
+    fn quorum_needs_a_submitter() {
+        let (mut app, contract_addr) = setup();
+
+        let err = set_policy(
+            &mut app,
+            &contract_addr,
+            BootstrapPolicy::Quorum {
+                submitters: 0,
+                window_seconds: 3600,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidBootstrapPolicy(
+                "quorum needs at least one submitter".to_string()
+            ),
+            err.downcast().unwrap()
+        );
+
+        let err = set_policy(
+            &mut app,
+            &contract_addr,
+            BootstrapPolicy::Quorum {
+                submitters: 2,
+                window_seconds: 0,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidBootstrapPolicy(
+                "quorum window must be greater than zero".to_string()
+            ),
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .execute_contract(
+                "updater1".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetBootstrapPolicy {
+                    policy: BootstrapPolicy::AdminOnly {},
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+    }
+}
+
+mod aggregation_tests {
+    use super::*;
+    use crate::aggregation::{
+        mad_filtered_aggregate, median, median_absolute_deviation, Submission, MIN_MAD_FRACTION,
+    };
+    use crate::msg::{AggregationConfig, BootstrapPolicy, Submitter, UpdaterStatusResponse};
+    use crate::ContractError;
+    use cosmwasm_std::{Addr, StdResult};
+    use proptest::prelude::*;
+
+    fn submissions(prices: &[u64]) -> Vec<Submission> {
+        prices
+            .iter()
+            .enumerate()
+            .map(|(i, price)| Submission {
+                updater: Addr::unchecked(format!("updater{}", i)),
+                price: Decimal256::from_ratio(*price, 100u128),
+                weight: Decimal::one(),
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn median_lies_within_submitted_range(
+            prices in prop::collection::vec(1u64..1_000_000, 1..20),
+        ) {
+            let subs = submissions(&prices);
+            let values: Vec<Decimal256> = subs.iter().map(|s| s.price).collect();
+            let median = median(&values).unwrap();
+
+            prop_assert!(values.iter().min().unwrap() <= &median);
+            prop_assert!(&median <= values.iter().max().unwrap());
+        }
+
+        #[test]
+        fn filtering_splits_submissions_at_k_mads(
+            prices in prop::collection::vec(1u64..1_000_000, 1..20),
+            k in 1u64..10,
+        ) {
+            let subs = submissions(&prices);
+            let values: Vec<Decimal256> = subs.iter().map(|s| s.price).collect();
+            let center = median(&values).unwrap();
+            let bound = median_absolute_deviation(&values)
+                .unwrap()
+                .max(center * MIN_MAD_FRACTION)
+                * Decimal256::from_ratio(k, 1u128);
+
+            let aggregate =
+                mad_filtered_aggregate(&subs, Decimal::from_ratio(k, 1u128), 1).unwrap();
+            prop_assert_eq!(subs.len(), aggregate.survivors.len() + aggregate.outliers.len());
+            for sub in &subs {
+                let flagged = aggregate.outliers.contains(&sub.updater);
+                prop_assert_eq!(sub.price.abs_diff(center) > bound, flagged);
+            }
+
+            // The price is the median of what survived
+            let surviving: Vec<Decimal256> = subs
+                .iter()
+                .filter(|s| aggregate.survivors.contains(&s.updater))
+                .map(|s| s.price)
+                .collect();
+            prop_assert_eq!(median(&surviving).unwrap(), aggregate.price);
+        }
+
+        #[test]
+        fn honest_majority_discards_extreme_submissions(
+            honest in prop::collection::vec(1_000u64..10_000, 3..12),
+            extremes in 0usize..6,
+        ) {
+            prop_assume!(extremes < honest.len());
+            let mut prices = honest.clone();
+            prices.extend(std::iter::repeat_n(u64::MAX / 2, extremes));
+            let subs = submissions(&prices);
+
+            let aggregate = mad_filtered_aggregate(&subs, Decimal::percent(300), 1).unwrap();
+            let lo = Decimal256::from_ratio(*honest.iter().min().unwrap(), 100u128);
+            let hi = Decimal256::from_ratio(*honest.iter().max().unwrap(), 100u128);
+            prop_assert!(lo <= aggregate.price && aggregate.price <= hi);
+            for sub in &subs[honest.len()..] {
+                prop_assert!(aggregate.outliers.contains(&sub.updater));
+            }
+        }
+
+        #[test]
+        fn too_few_survivors_fail(
+            prices in prop::collection::vec(1u64..1_000_000, 1..20),
+            extra in 1u32..5,
+        ) {
+            let subs = submissions(&prices);
+            let required = subs.len() as u32 + extra;
+
+            let err = mad_filtered_aggregate(&subs, Decimal::percent(300), required).unwrap_err();
+            let ContractError::InsufficientSubmissions { required: r, .. } = err else {
+                panic!("expected insufficient submissions, got {}", err);
+            };
+            prop_assert_eq!(required, r);
+        }
+    }
+
+    #[test]
+    fn agreeing_majority_tolerates_small_differences() {
+        // Most updaters agree exactly, so the MAD is zero
+        let subs = submissions(&[10000, 10000, 10000, 10001, 20000]);
+
+        let aggregate = mad_filtered_aggregate(&subs, Decimal::percent(300), 1).unwrap();
+        assert_eq!(Decimal256::from_ratio(100u128, 1u128), aggregate.price);
+        assert_eq!(vec![subs[4].updater.clone()], aggregate.outliers);
+    }
+
+    fn setup() -> (App, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let admin = "admin".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                admin,
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["atom".to_string()],
+                    whitelisted_updaters: (1..=5)
+                        .map(|i| format!("updater{}", i).into_addr().to_string())
+                        .collect(),
+                },
+                &[],
+                "Oracle Contract",
+                None,
+            )
+            .unwrap();
+
+        for msg in [
+            ExecuteMsg::SetBootstrapPolicy {
+                policy: BootstrapPolicy::Quorum {
+                    submitters: 4,
+                    window_seconds: 3600,
+                },
+            },
+            ExecuteMsg::SetAggregationConfig {
+                config: Some(AggregationConfig {
+                    outlier_mads: Decimal::percent(300),
+                    min_survivors: 4,
+                }),
+            },
+        ] {
+            app.execute_contract("admin".into_addr(), contract_addr.clone(), &msg, &[])
+                .unwrap();
+        }
+
+        (app, contract_addr)
+    }
+
+    fn submit(app: &mut App, contract_addr: &Addr, updater: u32, usd: u64) {
+        app.execute_contract(
+            format!("updater{}", updater).into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "atom".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(usd, 1u128),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn price(app: &App, contract_addr: &Addr) -> StdResult<TokenPriceResponse> {
+        app.wrap().query_wasm_smart(
+            contract_addr,
+            &QueryMsg::TokenPrice {
+                token_id: "atom".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn round_waits_for_enough_survivors_and_flags_outliers() {
+        let (mut app, contract_addr) = setup();
+
+        submit(&mut app, &contract_addr, 1, 10);
+        submit(&mut app, &contract_addr, 2, 11);
+        submit(&mut app, &contract_addr, 3, 12);
+        submit(&mut app, &contract_addr, 4, 1000);
+
+        // The quorum is reached, but only three submissions survive filtering
+        assert!(price(&app, &contract_addr).is_err());
+
+        submit(&mut app, &contract_addr, 5, 11);
+        let res = price(&app, &contract_addr).unwrap();
+        assert_eq!(Decimal256::from_ratio(11u128, 1u128), res.price);
+
+        let Submitter::Quorum { updaters, outliers } = res.submitter else {
+            panic!("expected a quorum submitter");
+        };
+        assert_eq!(4, updaters.len());
+        assert_eq!(vec!["updater4".into_addr().to_string()], outliers);
+
+        let status: UpdaterStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::UpdaterStatus {
+                    updater: "updater4".into_addr().to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(1, status.outlier_count);
+    }
+
+    #[test]
+    fn aggregation_config_is_validated() {
+        let (mut app, contract_addr) = setup();
+
+        for (config, error) in [
+            (
+                AggregationConfig {
+                    outlier_mads: Decimal::zero(),
+                    min_survivors: 1,
+                },
+                "outlier MADs must be greater than zero",
+            ),
+            (
+                AggregationConfig {
+                    outlier_mads: Decimal::one(),
+                    min_survivors: 0,
+                },
+                "at least one submission must survive",
+            ),
+        ] {
+            let err = app
+                .execute_contract(
+                    "admin".into_addr(),
+                    contract_addr.clone(),
+                    &ExecuteMsg::SetAggregationConfig {
+                        config: Some(config),
+                    },
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::InvalidAggregationConfig(error.to_string()),
+                err.downcast().unwrap()
+            );
+        }
+
+        app.execute_contract(
+            "admin".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetAggregationConfig { config: None },
+            &[],
+        )
+        .unwrap();
+        let res: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(&contract_addr, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(None, res.aggregation);
+    }
+}
+
+mod updater_weight_tests {
+    use super::*;
+    use crate::aggregation::{median, weighted_median};
+    use crate::msg::{
+        BootstrapPolicy, MigrateMsg, Submitter, SudoMsg, UpdaterStatusResponse,
+        UpdaterWeightsResponse,
+    };
+    use crate::ContractError;
+    use cosmwasm_std::{Addr, StdResult};
+    use cw_storage_plus::Map;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn equal_weights_give_the_plain_median(
+            prices in prop::collection::vec(1u64..1_000_000, 1..20),
+            weight in 1u64..100,
+        ) {
+            let prices: Vec<Decimal256> = prices
+                .iter()
+                .map(|p| Decimal256::from_ratio(*p, 100u128))
+                .collect();
+            let weighted: Vec<(Decimal256, Decimal)> = prices
+                .iter()
+                .map(|p| (*p, Decimal::from_ratio(weight, 1u128)))
+                .collect();
+
+            prop_assert_eq!(median(&prices), weighted_median(&weighted));
+        }
+
+        #[test]
+        fn weighted_median_lies_within_submitted_range(
+            submissions in prop::collection::vec((1u64..1_000_000, 1u64..100), 1..20),
+        ) {
+            let weighted: Vec<(Decimal256, Decimal)> = submissions
+                .iter()
+                .map(|(p, w)| (Decimal256::from_ratio(*p, 100u128), Decimal::percent(*w)))
+                .collect();
+            let median = weighted_median(&weighted).unwrap();
+
+            prop_assert!(weighted.iter().map(|(p, _)| *p).min().unwrap() <= median);
+            prop_assert!(median <= weighted.iter().map(|(p, _)| *p).max().unwrap());
+        }
+
+        #[test]
+        fn majority_weight_sets_the_price(
+            others in prop::collection::vec((1u64..1_000_000, 1u64..100), 0..10),
+            price in 1u64..1_000_000,
+        ) {
+            let others_weight: u64 = others.iter().map(|(_, w)| w).sum();
+            let mut weighted: Vec<(Decimal256, Decimal)> = others
+                .iter()
+                .map(|(p, w)| (Decimal256::from_ratio(*p, 1u128), Decimal::from_ratio(*w, 1u128)))
+                .collect();
+            weighted.push((
+                Decimal256::from_ratio(price, 1u128),
+                Decimal::from_ratio(others_weight + 1, 1u128),
+            ));
+
+            prop_assert_eq!(
+                Some(Decimal256::from_ratio(price, 1u128)),
+                weighted_median(&weighted)
+            );
+        }
+    }
+
+    fn setup() -> (App, u64, Addr) {
+        let mut app = App::default();
+        let code_id = app.store_code(gg_oracle_price_contract());
+
+        let owner = "owner".into_addr();
+
+        let contract_addr = app
+            .instantiate_contract(
+                code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    owner: None,
+                    admin: None,
+                    price_deviation_threshold: Decimal::percent(5),
+                    supported_tokens: vec!["atom".to_string()],
+                    whitelisted_updaters: (1..=3)
+                        .map(|i| format!("updater{}", i).into_addr().to_string())
+                        .collect(),
+                },
+                &[],
+                "Oracle Contract",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        (app, code_id, contract_addr)
+    }
+
+    fn set_weight(
+        app: &mut App,
+        contract_addr: &Addr,
+        updater: &str,
+        weight: Decimal,
+    ) -> anyhow::Result<()> {
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetUpdaterWeight {
+                updater: updater.into_addr().to_string(),
+                weight,
+            },
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn weights(app: &App, contract_addr: &Addr) -> UpdaterWeightsResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr,
+                &QueryMsg::UpdaterWeights {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    }
+
+    fn submit(app: &mut App, contract_addr: &Addr, updater: &str, usd: u64) {
+        app.execute_contract(
+            updater.into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateSinglePrice {
+                token_id: "atom".to_string(),
+                price_info: TokenPriceInfo {
+                    usd: Decimal256::from_ratio(usd, 1u128),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    fn price(app: &App, contract_addr: &Addr) -> StdResult<TokenPriceResponse> {
+        app.wrap().query_wasm_smart(
+            contract_addr,
+            &QueryMsg::TokenPrice {
+                token_id: "atom".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn updaters_start_with_equal_weight() {
+        let (app, _, contract_addr) = setup();
+
+        let res = weights(&app, &contract_addr);
+        assert_eq!(Decimal::from_ratio(3u128, 1u128), res.total_weight);
+        assert_eq!(3, res.weights.len());
+        assert!(res
+            .weights
+            .iter()
+            .all(|w| w.weight == Decimal::one() && w.share == Decimal::from_ratio(1u128, 3u128)));
+    }
+
+    #[test]
+    fn admin_sets_weights() {
+        let (mut app, _, contract_addr) = setup();
+
+        set_weight(
+            &mut app,
+            &contract_addr,
+            "updater1",
+            Decimal::from_ratio(2u128, 1u128),
+        )
+        .unwrap();
+
+        let res = weights(&app, &contract_addr);
+        assert_eq!(Decimal::from_ratio(4u128, 1u128), res.total_weight);
+        let updater1 = res
+            .weights
+            .iter()
+            .find(|w| w.updater == "updater1".into_addr().to_string())
+            .unwrap();
+        assert_eq!(Decimal::percent(50), updater1.share);
+
+        let status: UpdaterStatusResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &contract_addr,
+                &QueryMsg::UpdaterStatus {
+                    updater: "updater1".into_addr().to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(Decimal::from_ratio(2u128, 1u128), status.weight);
+
+        let err = set_weight(&mut app, &contract_addr, "updater1", Decimal::zero()).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidUpdaterWeight {},
+            err.downcast().unwrap()
+        );
+
+        let err = set_weight(&mut app, &contract_addr, "stranger", Decimal::one()).unwrap_err();
+        assert_eq!(
+            ContractError::UpdaterNotWhitelisted("stranger".into_addr().to_string()),
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .execute_contract(
+                "updater1".into_addr(),
+                contract_addr.clone(),
+                &ExecuteMsg::SetUpdaterWeight {
+                    updater: "updater1".into_addr().to_string(),
+                    weight: Decimal::from_ratio(10u128, 1u128),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn rounds_take_the_weighted_median() {
+        let (mut app, _, contract_addr) = setup();
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetBootstrapPolicy {
+                policy: BootstrapPolicy::Quorum {
+                    submitters: 3,
+                    window_seconds: 3600,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+        set_weight(
+            &mut app,
+            &contract_addr,
+            "updater3",
+            Decimal::from_ratio(3u128, 1u128),
+        )
+        .unwrap();
+
+        submit(&mut app, &contract_addr, "updater1", 10);
+        submit(&mut app, &contract_addr, "updater2", 20);
+        submit(&mut app, &contract_addr, "updater3", 30);
+
+        // updater3 holds 3 of 5 weight
+        assert_eq!(
+            Decimal256::from_ratio(30u128, 1u128),
+            price(&app, &contract_addr).unwrap().price
+        );
+    }
+
+    #[test]
+    fn removed_updaters_drop_out_of_rounds() {
+        let (mut app, _, contract_addr) = setup();
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::SetBootstrapPolicy {
+                policy: BootstrapPolicy::Quorum {
+                    submitters: 2,
+                    window_seconds: 3600,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+        submit(&mut app, &contract_addr, "updater1", 10);
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::RemoveWhitelistedUpdater {
+                updater: "updater1".into_addr().to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        submit(&mut app, &contract_addr, "updater2", 20);
+        assert!(price(&app, &contract_addr).is_err());
+
+        submit(&mut app, &contract_addr, "updater3", 22);
+        let res = price(&app, &contract_addr).unwrap();
+        assert_eq!(Decimal256::from_ratio(21u128, 1u128), res.price);
+        let Submitter::Quorum { updaters, .. } = res.submitter else {
+            panic!("expected a quorum submitter");
+        };
+        assert!(!updaters.contains(&"updater1".into_addr().to_string()));
+    }
+
+    #[test]
+    fn migrate_rewrites_whitelist_flags_as_weights() {
+        let (mut app, code_id, contract_addr) = setup();
+
+        // The previous version stored whitelisted updaters as flags
+        let legacy: Map<&Addr, bool> = Map::new("whitelisted_updaters");
+        let mut storage = app.contract_storage_mut(&contract_addr);
+        legacy
+            .save(storage.as_mut(), &"updater1".into_addr(), &true)
+            .unwrap();
+        legacy
+            .save(storage.as_mut(), &"updater2".into_addr(), &false)
+            .unwrap();
+        drop(storage);
+
+        app.migrate_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &MigrateMsg {},
+            code_id,
+        )
+        .unwrap();
+
+        let res = weights(&app, &contract_addr);
+        let updaters: Vec<_> = res.weights.iter().map(|w| w.updater.clone()).collect();
+        assert!(!updaters.contains(&"updater2".into_addr().to_string()));
+        assert_eq!(2, res.weights.len());
+        assert!(res.weights.iter().all(|w| w.weight == Decimal::one()));
+        assert_eq!(Decimal::from_ratio(2u128, 1u128), res.total_weight);
+    }
+
+    #[test]
+    fn total_weight_follows_the_whitelist() {
+        let (mut app, _, contract_addr) = setup();
+        set_weight(
+            &mut app,
+            &contract_addr,
+            "updater1",
+            Decimal::from_ratio(5u128, 1u128),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::RemoveWhitelistedUpdater {
+                updater: "updater1".into_addr().to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.wasm_sudo(
+            contract_addr.clone(),
+            &SudoMsg::RemoveUpdater {
+                updater: "updater2".into_addr().to_string(),
+            },
+        )
+        .unwrap();
+        app.execute_contract(
+            "owner".into_addr(),
+            contract_addr.clone(),
+            &ExecuteMsg::AddWhitelistedUpdater {
+                updater: "updater4".into_addr().to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let res = weights(&app, &contract_addr);
+        assert_eq!(Decimal::from_ratio(2u128, 1u128), res.total_weight);
+        assert!(res.weights.iter().all(|w| w.share == Decimal::percent(50)));
+    }
+}
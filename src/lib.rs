@@ -1,17 +1,24 @@
+#[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 
+pub mod aggregation;
 mod contract;
 pub mod error;
 pub mod helpers;
 pub mod msg;
 pub mod state;
 
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+
 // Export these for anyone using this contract as a dependency
 pub use crate::error::ContractError;
+pub use crate::helpers::{OracleContract, OracleQuerier};
 pub use crate::msg::{
-    AllPricesResponse, ConfigResponse, ExecuteMsg, InstantiateMsg, PendingUpdatesResponse,
-    PriceHistoryResponse, QueryMsg, TokenPriceResponse, WhitelistedUpdatersResponse,
+    AllPricesResponse, ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    PendingUpdatesResponse, PriceHistoryResponse, QueryMsg, SudoMsg, TokenPriceResponse,
+    TokenPricesResponse, WhitelistedUpdatersResponse,
 };
 
 #[cfg(test)]
@@ -19,7 +26,7 @@ mod integration_tests;
 
 // Entry points
 
-#[entry_point]
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
     env: Env,
@@ -29,7 +36,7 @@ pub fn instantiate(
     contract::instantiate(deps, env, info, msg)
 }
 
-#[entry_point]
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
     env: Env,
@@ -39,7 +46,17 @@ pub fn execute(
     contract::execute(deps, env, info, msg)
 }
 
-#[entry_point]
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     contract::query(deps, env, msg)
 }
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    contract::sudo(deps, env, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    contract::migrate(deps, env, msg)
+}
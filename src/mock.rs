@@ -0,0 +1,183 @@
+use anyhow::Result as AnyResult;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, Decimal, Decimal256, DepsMut, Empty, Env, MessageInfo, Response, StdResult, Storage,
+};
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use std::collections::HashMap;
+
+use crate::contract;
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, PriceData, Submitter, TokenPriceInfo};
+use crate::state::{Config, TokenInfo, CONFIG, PRICES, TOKENS};
+
+// Threshold used by both the mock and the preloaded real contract
+const MOCK_DEVIATION_THRESHOLD: u64 = 5;
+
+#[cw_serde]
+pub struct MockPrice {
+    pub token_id: String,
+    pub price: Decimal256,
+}
+
+#[cw_serde]
+pub struct MockInstantiateMsg {
+    pub prices: Vec<MockPrice>,
+}
+
+// Test-only messages; anyone may call them
+#[cw_serde]
+pub enum MockExecuteMsg {
+    // Set a price as of the current block, marking the token supported
+    SetPrice { token_id: String, price: Decimal256 },
+    // Backdate the stored price so it looks `age_seconds` old
+    SetStale { token_id: String, age_seconds: u64 },
+}
+
+// The real oracle contract, for use in an App
+pub fn oracle_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new_with_empty(contract::execute, contract::instantiate, contract::query)
+            .with_sudo_empty(contract::sudo)
+            .with_migrate_empty(contract::migrate),
+    )
+}
+
+// A mock oracle answering the real QueryMsg, with prices set directly via MockExecuteMsg
+pub fn mock_oracle_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new_with_empty(
+        mock_execute,
+        mock_instantiate,
+        contract::query,
+    ))
+}
+
+// Store and instantiate the real oracle owned by `owner`, with the given prices already set
+pub fn instantiate_oracle_with_prices(
+    app: &mut App,
+    owner: Addr,
+    prices: &[(&str, Decimal256)],
+) -> AnyResult<Addr> {
+    let code_id = app.store_code(oracle_contract());
+
+    let contract_addr = app.instantiate_contract(
+        code_id,
+        owner.clone(),
+        &InstantiateMsg {
+            owner: Some(owner.to_string()),
+            admin: None,
+            price_deviation_threshold: Decimal::percent(MOCK_DEVIATION_THRESHOLD),
+            whitelisted_updaters: vec![],
+            supported_tokens: prices
+                .iter()
+                .map(|(token_id, _)| token_id.to_string())
+                .collect(),
+        },
+        &[],
+        "GG Oracle Price",
+        None,
+    )?;
+
+    if !prices.is_empty() {
+        let prices: HashMap<String, TokenPriceInfo> = prices
+            .iter()
+            .map(|(token_id, usd)| (token_id.to_string(), TokenPriceInfo { usd: *usd }))
+            .collect();
+
+        app.execute_contract(
+            owner,
+            contract_addr.clone(),
+            &ExecuteMsg::UpdatePrices {
+                price_data: PriceData { prices },
+            },
+            &[],
+        )?;
+    }
+
+    Ok(contract_addr)
+}
+
+fn mock_instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: MockInstantiateMsg,
+) -> Result<Response, ContractError> {
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: info.sender.clone(),
+            admin: info.sender.clone(),
+            price_deviation_threshold: Decimal::percent(MOCK_DEVIATION_THRESHOLD),
+            paused: false,
+            guardian: None,
+            timelock_delay: 0,
+        },
+    )?;
+
+    for MockPrice { token_id, price } in msg.prices {
+        set_price(deps.storage, &env, &info.sender, &token_id, price)?;
+    }
+
+    Ok(Response::new().add_attribute("method", "mock_instantiate"))
+}
+
+fn mock_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: MockExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        MockExecuteMsg::SetPrice { token_id, price } => {
+            set_price(deps.storage, &env, &info.sender, &token_id, price)?;
+            Ok(Response::new()
+                .add_attribute("method", "mock_set_price")
+                .add_attribute("token_id", token_id))
+        }
+        MockExecuteMsg::SetStale {
+            token_id,
+            age_seconds,
+        } => {
+            let mut price = PRICES
+                .may_load(deps.storage, &token_id)?
+                .ok_or_else(|| ContractError::TokenNotSupported(token_id.clone()))?;
+            price.last_updated = env.block.time.seconds().saturating_sub(age_seconds);
+            PRICES.save(deps.storage, &token_id, &price)?;
+
+            Ok(Response::new()
+                .add_attribute("method", "mock_set_stale")
+                .add_attribute("token_id", token_id))
+        }
+    }
+}
+
+fn set_price(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    token_id: &str,
+    price: Decimal256,
+) -> StdResult<()> {
+    if !TOKENS.has(storage, token_id) {
+        TOKENS.save(
+            storage,
+            token_id,
+            &TokenInfo {
+                deviation_reference: None,
+                deviation_thresholds: None,
+                decimals: None,
+            },
+        )?;
+    }
+
+    contract::write_price(
+        storage,
+        token_id,
+        price,
+        env.block.time.seconds(),
+        Submitter::Updater {
+            address: sender.to_string(),
+        },
+    )
+}
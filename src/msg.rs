@@ -1,7 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{Decimal, Decimal256, Uint128, Uint256};
 use std::collections::HashMap;
 
+#[cw_serde]
+pub struct MigrateMsg {}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub owner: Option<String>,
@@ -13,8 +16,31 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub struct TokenPrice {
-    pub usd: Decimal,
+    pub usd: Decimal256,
     pub last_updated: u64,
+    #[serde(default)]
+    pub submitter: Submitter,
+}
+
+// Who put a price on chain
+#[cw_serde]
+pub enum Submitter {
+    Updater { address: String },
+    ApprovedBy { admin: String },
+    Governance {},
+    Derived {}, // Computed from other feeds
+    Quorum {
+        updaters: Vec<String>, // Submissions the price was aggregated from
+        #[serde(default)]
+        outliers: Vec<String>, // Submissions discarded by outlier filtering
+    },
+    Unknown {}, // Written before submitters were recorded
+}
+
+impl Default for Submitter {
+    fn default() -> Self {
+        Submitter::Unknown {}
+    }
 }
 
 // Format serupa dengan yang Anda berikan
@@ -25,7 +51,119 @@ pub struct PriceData {
 
 #[cw_serde]
 pub struct TokenPriceInfo {
-    pub usd: Decimal,
+    pub usd: Decimal256,
+}
+
+// Reference price that new submissions are compared against for deviation checks
+#[cw_serde]
+pub enum DeviationReference {
+    LastPrice {},
+    Twap { window_seconds: u64 },
+    Ema { period: u64 },
+}
+
+// Per-token deviation thresholds, overriding the global symmetric threshold
+#[cw_serde]
+pub struct DeviationThresholds {
+    pub up: Decimal,   // Allowed move above the reference price
+    pub down: Decimal, // Allowed move below the reference price
+    pub time_scaling: Option<TimeScaling>,
+}
+
+// Scales thresholds by sqrt(seconds since last update / reference_seconds),
+// clamped to [min_multiplier, max_multiplier]
+#[cw_serde]
+pub struct TimeScaling {
+    pub reference_seconds: u64,
+    pub min_multiplier: Decimal,
+    pub max_multiplier: Decimal,
+}
+
+// Basket or index feed priced as the weighted sum of its component prices
+#[cw_serde]
+pub struct CompositeFeed {
+    pub components: Vec<FeedComponent>,
+    pub divisor: Option<Decimal>, // None leaves the weighted sum as is
+}
+
+#[cw_serde]
+pub struct FeedComponent {
+    pub token_id: String,
+    pub weight: Decimal,
+}
+
+// Feed priced from an underlying feed and inputs submitted by updaters
+#[cw_serde]
+pub enum DerivedFeed {
+    // Liquid staking token: underlying price times the exchange rate
+    StakingDerivative { underlying: String },
+    // Two-asset constant-product LP share, priced from the pool invariant rather than
+    // the spot reserves so that moving reserves within a block does not move the price
+    LpShare { token_a: String, token_b: String },
+}
+
+impl DerivedFeed {
+    // Tokens the feed is priced from
+    pub fn sources(&self) -> Vec<&str> {
+        match self {
+            DerivedFeed::StakingDerivative { underlying } => vec![underlying],
+            DerivedFeed::LpShare { token_a, token_b } => vec![token_a, token_b],
+        }
+    }
+}
+
+// Latest updater input for a derived feed
+#[cw_serde]
+pub enum DerivedInput {
+    ExchangeRate { rate: Decimal }, // Underlying units per staking token
+    // In base units, scaled to whole tokens by each token's decimals when set
+    Reserves {
+        reserve_a: Uint128,
+        reserve_b: Uint128,
+        total_supply: Uint128, // LP shares outstanding
+    },
+}
+
+// Bond updaters must hold to submit prices; unset leaves whitelisting as the only requirement
+#[cw_serde]
+pub struct BondingConfig {
+    pub denom: String,
+    pub min_bond: Uint128,        // Bond needed to submit prices
+    pub unbonding_period: u64,    // Seconds before unbonded funds can be claimed
+    pub rejection_slash: Decimal, // Share of the bond slashed when a submission is rejected
+    pub treasury: String,         // Receives slashed funds
+}
+
+// How the first price of a token is accepted, before there is a price to check deviation against
+#[cw_serde]
+pub enum BootstrapPolicy {
+    Trusted {},   // Any updater's first submission is written
+    AdminOnly {}, // Updater submissions wait for admin approval; admins write directly
+    // The median is written once this many updaters have submitted within the window
+    Quorum {
+        submitters: u32,
+        window_seconds: u64, // Older submissions no longer count toward the round
+    },
+}
+
+impl Default for BootstrapPolicy {
+    fn default() -> Self {
+        BootstrapPolicy::Trusted {}
+    }
+}
+
+// Outlier filtering for multi-submitter rounds; unset takes the plain median
+#[cw_serde]
+pub struct AggregationConfig {
+    pub outlier_mads: Decimal, // Submissions further than this many MADs from the median are discarded
+    pub min_survivors: u32,    // Submissions that must remain after filtering
+}
+
+// Reward paid to updaters from the pool for each accepted price they submit
+#[cw_serde]
+pub struct RewardConfig {
+    pub denom: String,
+    pub per_submission: Uint128, // Paid per token price written
 }
 
 #[cw_serde]
@@ -36,16 +174,94 @@ pub enum ExecuteMsg {
     SetDeviationThreshold { threshold: Decimal },
     AddWhitelistedUpdater { updater: String },
     RemoveWhitelistedUpdater { updater: String },
+    // Weight in bootstrap quorum rounds, the only prices aggregated across updaters;
+    // later prices come from a single updater and are checked for deviation instead
+    SetUpdaterWeight { updater: String, weight: Decimal },
+    Pause {},   // Block price updates and approvals
+    Unpause {},
     AddSupportedToken { token_id: String },
     RemoveSupportedToken { token_id: String },
+    SetEmaPeriods { half_lives: Vec<u64> }, // EMA half-lives in seconds
+    SetDeviationReference { token_id: String, reference: DeviationReference },
+    SetTokenDeviationThresholds {
+        token_id: String,
+        thresholds: Option<DeviationThresholds>, // None falls back to the global threshold
+    },
+    SetCompositeFeed {
+        token_id: String,
+        feed: Option<CompositeFeed>, // None lets updaters submit the token again
+    },
+    SetDerivedFeed {
+        token_id: String,
+        feed: Option<DerivedFeed>, // None lets updaters submit the token again
+    },
+    SetTokenDecimals { token_id: String, decimals: Option<u8> },
+    SetBootstrapPolicy { policy: BootstrapPolicy },
+    SetAggregationConfig { config: Option<AggregationConfig> }, // None takes the plain median
+
+    // Timelock functions
+    SetTimelockDelay { delay_seconds: u64 },  // Owner only, 0 applies changes immediately
+    SetGuardian { guardian: Option<String> }, // Owner only
+    ExecuteQueued { id: u64 },                // Anyone, once the delay has passed
+    CancelQueued { id: u64 },                 // Owner or guardian
+
+    // Bonding functions
+    SetBondingConfig { config: Option<BondingConfig> },  // Admin only, None disables bonding
+    Bond {},                                             // Whitelisted updaters, paid in the bond denom
+    Unbond { amount: Uint128 },
+    ClaimUnbonded {},                                    // Once the unbonding period has passed
+    Slash { updater: String, amount: Uint128 },          // Admin only, sends funds to the treasury
+
+    // Reward functions
+    SetRewardConfig { config: RewardConfig }, // Admin only
+    FundRewards {},                           // Anyone, paid in the reward denom
+    ClaimRewards {},
 
     // Price update functions from backend
     UpdatePrices { price_data: PriceData },
     UpdateSinglePrice { token_id: String, price_info: TokenPriceInfo },
+    UpdateDerivedInput { token_id: String, input: DerivedInput },
 
     // Manual admin actions
-    ApprovePrice { token_id: String, price: Decimal },
-    RejectPrice { token_id: String },
+    ApprovePrice { token_id: String, price: Decimal256 },
+    RejectPrice { token_id: String, reason: Option<String> },
+    ApprovePendingUpdate { id: u64, price: Option<Decimal256> }, // None writes the submitted price
+    RejectPendingUpdate { id: u64, reason: Option<String> },
+}
+
+impl ExecuteMsg {
+    // Risk parameter changes that wait out the timelock delay
+    pub fn is_timelocked(&self) -> bool {
+        matches!(
+            self,
+            ExecuteMsg::SetDeviationThreshold { .. }
+                | ExecuteMsg::AddWhitelistedUpdater { .. }
+                | ExecuteMsg::SetUpdaterWeight { .. }
+                | ExecuteMsg::AddSupportedToken { .. }
+                | ExecuteMsg::RemoveSupportedToken { .. }
+                | ExecuteMsg::SetEmaPeriods { .. }
+                | ExecuteMsg::SetDeviationReference { .. }
+                | ExecuteMsg::SetTokenDeviationThresholds { .. }
+                | ExecuteMsg::SetTimelockDelay { .. }
+                | ExecuteMsg::SetBondingConfig { .. }
+                | ExecuteMsg::SetCompositeFeed { .. }
+                | ExecuteMsg::SetDerivedFeed { .. }
+                | ExecuteMsg::SetTokenDecimals { .. }
+                | ExecuteMsg::SetBootstrapPolicy { .. }
+                | ExecuteMsg::SetAggregationConfig { .. }
+        )
+    }
+}
+
+// Privileged actions for chains where the oracle is governance-owned
+#[cw_serde]
+pub enum SudoMsg {
+    UpdateOwner { new_owner: String },
+    UpdateAdmin { new_admin: String },
+    Pause {},
+    Unpause {},
+    ForcePrice { token_id: String, price: Decimal256 }, // Bypasses pause and deviation checks
+    RemoveUpdater { updater: String },
 }
 
 #[cw_serde]
@@ -57,8 +273,35 @@ pub enum QueryMsg {
     #[returns(TokenPriceResponse)]
     TokenPrice { token_id: String },
 
+    #[returns(TokenConfigResponse)]
+    TokenConfig { token_id: String },
+
+    // Token price inverted to tokens per USD and/or scaled to one base unit
+    #[returns(TransformedPriceResponse)]
+    TransformedPrice {
+        token_id: String,
+        invert: bool,
+        per_base_unit: bool, // Uses the token's decimals
+    },
+
+    #[returns(TokenPricesResponse)]
+    TokenPrices {
+        token_ids: Vec<String>,
+        max_age_seconds: Option<u64>, // Prices older than this are reported as stale
+    },
+
+    // Predict what UpdatePrices from the sender would do, without writing
+    #[returns(SimulateUpdateResponse)]
+    SimulateUpdate {
+        sender: String,
+        price_data: PriceData,
+    },
+
     #[returns(AllPricesResponse)]
-    AllPrices {},
+    AllPrices {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     #[returns(PriceHistoryResponse)]
     PriceHistory {
@@ -68,14 +311,90 @@ pub enum QueryMsg {
         limit: Option<u32>,
     },
 
+    #[returns(TwapResponse)]
+    Twap { token_id: String, window_seconds: u64 },
+
+    #[returns(EmaResponse)]
+    Ema { token_id: String, period: u64 }, // Period is one of the configured half-lives
+
+    #[returns(PriceStatsResponse)]
+    PriceStats { token_id: String, window_seconds: u64 },
+
+    // Pending updates across all tokens, oldest first
+    #[returns(PendingUpdatesResponse)]
+    PendingUpdates {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    // Pending updates queued for one token, oldest first
     #[returns(PendingUpdatesResponse)]
-    PendingUpdates {},
+    TokenPendingUpdates {
+        token_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    // Approved and rejected updates for a token, oldest first
+    #[returns(ResolvedUpdatesResponse)]
+    ResolvedUpdates {
+        token_id: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 
     #[returns(SupportedTokensResponse)]
-    SupportedTokens {},
+    SupportedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 
     #[returns(WhitelistedUpdatersResponse)]
-    WhitelistedUpdaters {},
+    WhitelistedUpdaters {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // Whitelisted updaters' weights in bootstrap quorum rounds
+    #[returns(UpdaterWeightsResponse)]
+    UpdaterWeights {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    #[returns(UpdaterStatusResponse)]
+    UpdaterStatus { updater: String },
+
+    #[returns(BondResponse)]
+    Bond { updater: String },
+
+    #[returns(RewardPoolResponse)]
+    RewardPool {},
+
+    #[returns(PendingRewardsResponse)]
+    PendingRewards { updater: String },
+
+    // Status of every whitelisted updater, including ones that never submitted
+    #[returns(UpdaterStatusesResponse)]
+    UpdaterStatuses {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    // Configuration changes waiting out the timelock, oldest first
+    #[returns(QueuedChangesResponse)]
+    QueuedChanges {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+
+    // Privileged actions, oldest first, optionally of one kind
+    #[returns(AuditLogResponse)]
+    AuditLog {
+        action: Option<AuditAction>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -83,24 +402,120 @@ pub struct ConfigResponse {
     pub owner: String,
     pub admin: String,
     pub price_deviation_threshold: Decimal,
+    pub paused: bool,
+    pub ema_half_lives: Vec<u64>,
+    pub guardian: Option<String>,
+    pub timelock_delay: u64,
+    pub bonding: Option<BondingConfig>,
+    pub bootstrap_policy: BootstrapPolicy,
+    pub aggregation: Option<AggregationConfig>,
+}
+
+#[cw_serde]
+pub struct RewardPoolResponse {
+    pub config: Option<RewardConfig>,
+    pub available: Uint128, // Funds not yet earned by any updater
+    pub accrued: Uint128,   // Funds earned but not yet claimed
+}
+
+#[cw_serde]
+pub struct PendingRewardsResponse {
+    pub updater: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct BondResponse {
+    pub updater: String,
+    pub bonded: Uint128,
+    pub unbonding: Uint128,
+    pub release_at: Option<u64>, // When unbonding funds can be claimed
+    pub active: bool,            // Whitelisted and bonded enough to submit prices
 }
 
 #[cw_serde]
 pub struct TokenPriceResponse {
     pub token_id: String,
-    pub price: Decimal,
+    pub price: Decimal256,
+    pub last_updated: u64,
+    pub submitter: Submitter,
+}
+
+#[cw_serde]
+pub struct TransformedPriceResponse {
+    pub token_id: String,
+    pub price: Decimal256, // USD per token, or tokens per USD when inverted
+    pub atomics: Uint256,  // Price = atomics / 10^exponent, keeping digits past the 18th
+    pub exponent: u32,
+    pub inverted: bool,
+    pub decimals: Option<u8>, // Set when the price is per base unit
     pub last_updated: u64,
 }
 
+#[cw_serde]
+pub struct TokenConfigResponse {
+    pub token_id: String,
+    pub deviation_reference: DeviationReference,
+    pub deviation_thresholds: Option<DeviationThresholds>,
+    pub composite: Option<CompositeFeed>,
+    pub derived: Option<DerivedFeed>,
+    pub derived_input: Option<DerivedInput>,
+    pub decimals: Option<u8>,
+}
+
+#[cw_serde]
+pub enum PriceStatus {
+    Ok,
+    Missing,
+    Unsupported,
+    Stale,
+}
+
+#[cw_serde]
+pub enum SimulatedOutcome {
+    Updated,
+    Pending,
+    Collecting {}, // Counted toward the first-price quorum
+    Failed { error: String },
+}
+
+#[cw_serde]
+pub struct SimulatedPrice {
+    pub token_id: String,
+    pub outcome: SimulatedOutcome,
+    pub deviation: Option<Decimal256>, // None without a previous price
+}
+
+#[cw_serde]
+pub struct SimulateUpdateResponse {
+    pub applies: bool,                // False if any token fails, reverting the whole batch
+    pub results: Vec<SimulatedPrice>, // Ordered by token ID
+}
+
+#[cw_serde]
+pub struct TokenPriceItem {
+    pub token_id: String,
+    pub status: PriceStatus,
+    pub price: Option<Decimal256>,
+    pub last_updated: Option<u64>,
+}
+
+#[cw_serde]
+pub struct TokenPricesResponse {
+    pub prices: Vec<TokenPriceItem>, // Same order as the requested token IDs
+}
+
 #[cw_serde]
 pub struct AllPricesResponse {
-    pub prices: HashMap<String, TokenPrice>,
+    pub prices: Vec<TokenPriceResponse>, // Ordered by token ID
+    pub next_start_after: Option<String>,
 }
 
 #[cw_serde]
 pub struct PriceHistoryEntry {
-    pub price: Decimal,
+    pub price: Decimal256,
     pub timestamp: u64,
+    pub submitter: Submitter,
 }
 
 #[cw_serde]
@@ -109,26 +524,222 @@ pub struct PriceHistoryResponse {
     pub history: Vec<PriceHistoryEntry>,
 }
 
+#[cw_serde]
+pub struct TwapResponse {
+    pub token_id: String,
+    pub window_seconds: u64,
+    pub twap: Decimal256,
+}
+
+#[cw_serde]
+pub struct EmaResponse {
+    pub token_id: String,
+    pub period: u64,
+    pub value: Decimal256,
+    pub last_updated: u64,
+}
+
+#[cw_serde]
+pub struct PriceStatsResponse {
+    pub token_id: String,
+    pub window_seconds: u64,
+    pub sample_count: u32,
+    pub truncated: bool, // The window held more samples than the cap, so only the latest are used
+    pub min: Option<Decimal256>,
+    pub max: Option<Decimal256>,
+    pub mean: Option<Decimal256>,
+    pub last: Option<Decimal256>,
+    pub volatility: Option<Decimal>, // Std dev of log returns between consecutive samples
+    pub max_drawdown: Option<Decimal>, // Largest peak-to-trough drop as a fraction of the peak
+}
+
 #[cw_serde]
 pub struct PendingUpdate {
+    #[serde(default)]
+    pub id: u64, // Assigned in submission order
     pub token_id: String,
-    pub current_price: Decimal,
-    pub new_price: Decimal,
-    pub percent_change: Decimal,
+    pub current_price: Decimal256,
+    pub new_price: Decimal256,
+    pub percent_change: Decimal256,
     pub requested_at: u64,
+    #[serde(default)]
+    pub submitter: Submitter,
 }
 
 #[cw_serde]
 pub struct PendingUpdatesResponse {
     pub updates: Vec<PendingUpdate>,
+    pub next_start_after: Option<u64>,
+}
+
+#[cw_serde]
+pub enum Resolution {
+    Approved { price: Decimal256 }, // Price actually written, which may differ from the submission
+    Rejected { reason: Option<String> },
+    Superseded { by: Option<u64> }, // Pending update that replaced it, None for a forced price
+}
+
+// A pending update after an admin acted on it
+#[cw_serde]
+pub struct ResolvedUpdate {
+    pub id: u64,
+    pub update: PendingUpdate,
+    pub resolution: Resolution,
+    pub resolved_by: Option<String>, // None for sudo (governance) actions
+    pub resolved_at: u64,
+}
+
+#[cw_serde]
+pub struct ResolvedUpdatesResponse {
+    pub updates: Vec<ResolvedUpdate>,
+    pub next_start_after: Option<u64>,
+}
+
+// A configuration change waiting out the timelock
+#[cw_serde]
+pub struct QueuedChange {
+    pub id: u64,
+    pub change: ExecuteMsg,
+    pub proposer: String, // Authorization is checked again as this address on execution
+    pub queued_at: u64,
+    pub execute_after: u64,
+}
+
+#[cw_serde]
+pub struct QueuedChangesResponse {
+    pub changes: Vec<QueuedChange>,
+    pub next_start_after: Option<u64>,
 }
 
 #[cw_serde]
 pub struct WhitelistedUpdatersResponse {
     pub updaters: Vec<String>,
+    pub next_start_after: Option<String>,
+}
+
+#[cw_serde]
+pub struct UpdaterWeight {
+    pub updater: String,
+    pub weight: Decimal,
+    pub share: Decimal, // Weight as a fraction of the total, for bootstrap quorum medians
+}
+
+#[cw_serde]
+pub struct UpdaterWeightsResponse {
+    pub weights: Vec<UpdaterWeight>,
+    pub total_weight: Decimal,
+    pub next_start_after: Option<String>,
+}
+
+#[cw_serde]
+pub struct UpdaterStatusResponse {
+    pub updater: String,
+    pub whitelisted: bool,
+    pub last_submission: Option<u64>,
+    pub seconds_since_last_submission: Option<u64>,
+    pub submission_count: u64,
+    pub pending_count: u64,
+    pub outlier_count: u64,
+    pub tokens_covered: Vec<String>,
+    pub weight: Decimal, // Weight in round aggregation, zero when not whitelisted
+}
+
+#[cw_serde]
+pub struct UpdaterStatusesResponse {
+    pub updaters: Vec<UpdaterStatusResponse>,
+    pub next_start_after: Option<String>,
+}
+
+// Kinds of privileged actions kept in the audit log
+#[cw_serde]
+#[derive(Copy)]
+pub enum AuditAction {
+    UpdateOwner,
+    UpdateAdmin,
+    SetDeviationThreshold,
+    AddWhitelistedUpdater,
+    RemoveWhitelistedUpdater,
+    Pause,
+    Unpause,
+    AddSupportedToken,
+    RemoveSupportedToken,
+    SetEmaPeriods,
+    SetDeviationReference,
+    SetTokenDeviationThresholds,
+    ApprovePrice,
+    RejectPrice,
+    ForcePrice,
+    SetTimelockDelay,
+    SetGuardian,
+    QueueChange,
+    CancelQueuedChange,
+    SetBondingConfig,
+    Slash,
+    SetRewardConfig,
+    SetCompositeFeed,
+    SetDerivedFeed,
+    SetTokenDecimals,
+    SetBootstrapPolicy,
+    SetAggregationConfig,
+    SetUpdaterWeight,
+}
+
+impl AuditAction {
+    // Storage key for the per-action index
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::UpdateOwner => "update_owner",
+            AuditAction::UpdateAdmin => "update_admin",
+            AuditAction::SetDeviationThreshold => "set_deviation_threshold",
+            AuditAction::AddWhitelistedUpdater => "add_whitelisted_updater",
+            AuditAction::RemoveWhitelistedUpdater => "remove_whitelisted_updater",
+            AuditAction::Pause => "pause",
+            AuditAction::Unpause => "unpause",
+            AuditAction::AddSupportedToken => "add_supported_token",
+            AuditAction::RemoveSupportedToken => "remove_supported_token",
+            AuditAction::SetEmaPeriods => "set_ema_periods",
+            AuditAction::SetDeviationReference => "set_deviation_reference",
+            AuditAction::SetTokenDeviationThresholds => "set_token_deviation_thresholds",
+            AuditAction::ApprovePrice => "approve_price",
+            AuditAction::RejectPrice => "reject_price",
+            AuditAction::ForcePrice => "force_price",
+            AuditAction::SetTimelockDelay => "set_timelock_delay",
+            AuditAction::SetGuardian => "set_guardian",
+            AuditAction::QueueChange => "queue_change",
+            AuditAction::CancelQueuedChange => "cancel_queued_change",
+            AuditAction::SetBondingConfig => "set_bonding_config",
+            AuditAction::Slash => "slash",
+            AuditAction::SetRewardConfig => "set_reward_config",
+            AuditAction::SetCompositeFeed => "set_composite_feed",
+            AuditAction::SetDerivedFeed => "set_derived_feed",
+            AuditAction::SetTokenDecimals => "set_token_decimals",
+            AuditAction::SetBootstrapPolicy => "set_bootstrap_policy",
+            AuditAction::SetAggregationConfig => "set_aggregation_config",
+            AuditAction::SetUpdaterWeight => "set_updater_weight",
+        }
+    }
+}
+
+#[cw_serde]
+pub struct AuditEntry {
+    pub id: u64,
+    pub actor: Option<String>, // None for sudo (governance) actions
+    pub action: AuditAction,
+    pub token_id: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub height: u64,
+    pub timestamp: u64,
+}
+
+#[cw_serde]
+pub struct AuditLogResponse {
+    pub entries: Vec<AuditEntry>,
+    pub next_start_after: Option<u64>,
 }
 
 #[cw_serde]
 pub struct SupportedTokensResponse {
     pub tokens: Vec<String>,
+    pub next_start_after: Option<String>,
 }
@@ -1,20 +1,101 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, Decimal, Decimal256, Empty, Uint128};
 use cw_storage_plus::{Item, Map};
 
-
-use crate::msg::{PendingUpdate, TokenPrice};
+use crate::msg::{
+    AggregationConfig, AuditEntry, BondingConfig, BootstrapPolicy, CompositeFeed, DerivedFeed,
+    DerivedInput, DeviationReference, DeviationThresholds, PendingUpdate, QueuedChange,
+    ResolvedUpdate, RewardConfig, Submitter, TokenPrice,
+};
 
 #[cw_serde]
 pub struct Config {
     pub owner: Addr,
     pub admin: Addr,
     pub price_deviation_threshold: Decimal,
+    #[serde(default)]
+    pub paused: bool,
+    #[serde(default)]
+    pub guardian: Option<Addr>, // May cancel queued changes alongside the owner
+    #[serde(default)]
+    pub timelock_delay: u64, // Seconds; 0 applies changes immediately
 }
 
 #[cw_serde]
 pub struct TokenInfo {
-    pub supported: bool,
+    pub deviation_reference: Option<DeviationReference>, // None compares against the last price
+    pub deviation_thresholds: Option<DeviationThresholds>, // None uses the global threshold
+    // Base units per whole token, as a power of ten
+    #[serde(default)]
+    pub decimals: Option<u8>,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct UpdaterStats {
+    pub last_submission: u64,
+    pub submission_count: u64, // Prices submitted, one per token
+    pub pending_count: u64,    // Submissions that went to pending updates
+    #[serde(default)]
+    pub outlier_count: u64, // Round submissions discarded as outliers
+    pub tokens_covered: Vec<String>, // Sorted token IDs this updater has submitted
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct Bond {
+    pub bonded: Uint128,
+    pub unbonding: Uint128,
+    pub release_at: u64, // When unbonding funds can be claimed
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct RewardPool {
+    pub available: Uint128, // Funds not yet earned by any updater
+    pub accrued: Uint128,   // Funds earned but not yet claimed
+}
+
+#[cw_serde]
+#[serde(from = "StoredPriceRecord")]
+pub struct PriceRecord {
+    pub price: Decimal256,
+    pub submitter: Submitter,
+}
+
+// History entries as stored by any contract version, so old entries need no rewrite
+#[cw_serde]
+#[serde(untagged)]
+enum StoredPriceRecord {
+    Current {
+        price: Decimal256,
+        submitter: Submitter,
+    },
+    Legacy(Decimal256), // Before submitters were recorded
+}
+
+impl From<StoredPriceRecord> for PriceRecord {
+    fn from(stored: StoredPriceRecord) -> Self {
+        match stored {
+            StoredPriceRecord::Current { price, submitter } => PriceRecord { price, submitter },
+            StoredPriceRecord::Legacy(price) => PriceRecord {
+                price,
+                submitter: Submitter::Unknown {},
+            },
+        }
+    }
+}
+
+#[cw_serde]
+pub struct EmaValue {
+    pub value: Decimal256,
+    pub last_updated: u64,
+}
+
+#[cw_serde]
+pub struct BootstrapSubmission {
+    pub price: Decimal256,
+    pub submitted_at: u64,
 }
 
 // Store the main config
@@ -24,13 +105,99 @@ pub const CONFIG: Item<Config> = Item::new("config");
 pub const PRICES: Map<&str, TokenPrice> = Map::new("prices");
 
 // Store price history
-pub const PRICE_HISTORY: Map<(&str, u64), Decimal> = Map::new("price_history");
+pub const PRICE_HISTORY: Map<(&str, u64), PriceRecord> = Map::new("price_history");
+
+// Store pending updates that need approval by ID
+pub const PENDING_UPDATES: Map<u64, PendingUpdate> = Map::new("pending_update_queue");
+
+// Index of pending update IDs by token
+pub const PENDING_UPDATES_BY_TOKEN: Map<(&str, u64), Empty> = Map::new("pending_updates_by_token");
 
-// Store pending updates that need approval
-pub const PENDING_UPDATES: Map<&str, PendingUpdate> = Map::new("pending_updates");
+// Index of pending update IDs by submitting updater
+pub const PENDING_UPDATES_BY_UPDATER: Map<(&str, u64), Empty> =
+    Map::new("pending_updates_by_updater");
+
+// Next pending update ID
+pub const PENDING_UPDATES_NEXT_ID: Item<u64> = Item::new("pending_updates_next_id");
+
+// Store approved and rejected updates per token by resolution ID
+pub const RESOLVED_UPDATES: Map<(&str, u64), ResolvedUpdate> = Map::new("resolved_updates");
+
+// Next resolution ID
+pub const RESOLVED_UPDATES_NEXT_ID: Item<u64> = Item::new("resolved_updates_next_id");
+
+// Store configuration changes waiting out the timelock by ID
+pub const QUEUED_CHANGES: Map<u64, QueuedChange> = Map::new("queued_changes");
+
+// Next queued change ID
+pub const QUEUED_CHANGES_NEXT_ID: Item<u64> = Item::new("queued_changes_next_id");
 
 // Store supported tokens
 pub const TOKENS: Map<&str, TokenInfo> = Map::new("tokens");
 
-// Store whitelisted updaters
-pub const WHITELISTED_UPDATERS: Map<&Addr, bool> = Map::new("whitelisted_updaters");
+// Store composite feed definitions by token
+pub const COMPOSITE_FEEDS: Map<&str, CompositeFeed> = Map::new("composite_feeds");
+
+// Store derived feed definitions by token
+pub const DERIVED_FEEDS: Map<&str, DerivedFeed> = Map::new("derived_feeds");
+
+// Store the latest updater input for each derived feed
+pub const DERIVED_INPUTS: Map<&str, DerivedInput> = Map::new("derived_inputs");
+
+// Inputs of derived prices waiting in pending updates, by pending update ID
+pub const PENDING_DERIVED_INPUTS: Map<u64, DerivedInput> = Map::new("pending_derived_inputs");
+
+// Index of composite and derived feeds by the token they are computed from
+pub const FEED_DEPENDENTS: Map<(&str, &str), Empty> = Map::new("feed_dependents");
+
+// Store whitelisted updaters with their weight in round aggregation
+pub const WHITELISTED_UPDATERS: Map<&Addr, Decimal> = Map::new("whitelisted_updaters");
+
+// Sum of the whitelisted updaters' weights
+pub const TOTAL_UPDATER_WEIGHT: Item<Decimal> = Item::new("total_updater_weight");
+
+// Store how first prices are accepted, absent while first submissions are trusted
+pub const BOOTSTRAP_POLICY: Item<BootstrapPolicy> = Item::new("bootstrap_policy");
+
+// Store outlier filtering for rounds, absent while rounds take the plain median
+pub const AGGREGATION: Item<AggregationConfig> = Item::new("aggregation");
+
+// Store first-price submissions per token and updater while a quorum is collected
+pub const BOOTSTRAP_SUBMISSIONS: Map<(&str, &Addr), BootstrapSubmission> =
+    Map::new("bootstrap_submissions");
+
+// Store the bonding requirements, absent while bonding is disabled
+pub const BONDING: Item<BondingConfig> = Item::new("bonding");
+
+// Store updater bonds
+pub const BONDS: Map<&Addr, Bond> = Map::new("bonds");
+
+// Total bonded and unbonding funds held by the contract
+pub const TOTAL_BONDED: Item<Uint128> = Item::new("total_bonded");
+
+// Store the updater reward rate, absent while rewards are disabled
+pub const REWARDS: Item<RewardConfig> = Item::new("rewards");
+
+// Reward funds held by the contract
+pub const REWARD_POOL: Item<RewardPool> = Item::new("reward_pool");
+
+// Store rewards earned but not yet claimed per updater
+pub const ACCRUED_REWARDS: Map<&Addr, Uint128> = Map::new("accrued_rewards");
+
+// Store configured EMA half-lives in seconds
+pub const EMA_PERIODS: Item<Vec<u64>> = Item::new("ema_periods");
+
+// Store EMA values per token and half-life
+pub const EMAS: Map<(&str, u64), EmaValue> = Map::new("emas");
+
+// Store per-updater submission stats
+pub const UPDATER_STATS: Map<&Addr, UpdaterStats> = Map::new("updater_stats");
+
+// Store the append-only audit log of privileged actions by ID
+pub const AUDIT_LOG: Map<u64, AuditEntry> = Map::new("audit_log");
+
+// Index of audit log IDs by action kind
+pub const AUDIT_LOG_BY_ACTION: Map<(&str, u64), Empty> = Map::new("audit_log_by_action");
+
+// Next audit log ID
+pub const AUDIT_LOG_NEXT_ID: Item<u64> = Item::new("audit_log_next_id");